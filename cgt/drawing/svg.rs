@@ -107,6 +107,26 @@ pub struct Grid {
     pub tile_size: u32,
 }
 
+/// Mapping from logical coordinates, e.g. tile positions, to SVG pixel coordinates
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    /// Size of logical unit in pixels
+    pub scale: f32,
+
+    /// Pixel position of logical point `(0, 0)`
+    pub origin: (i32, i32),
+}
+
+impl Viewport {
+    /// Map logical point to pixel coordinates. Fractional pixels are truncated.
+    pub fn map(&self, x: f32, y: f32) -> (i32, i32) {
+        (
+            self.origin.0 + (x * self.scale) as i32,
+            self.origin.1 + (y * self.scale) as i32,
+        )
+    }
+}
+
 impl ImmSvg {
     /// Create new SVG
     pub fn new<W>(
@@ -217,3 +237,24 @@ impl ImmSvg {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn viewport_scales_coordinates() {
+        let viewport = Viewport {
+            scale: 2.0,
+            origin: (0, 0),
+        };
+        assert_eq!(viewport.map(3.0, 5.0), (6, 10));
+        assert_eq!(viewport.map(0.5, 1.25), (1, 2));
+
+        let viewport = Viewport {
+            scale: 48.0,
+            origin: (2, 2),
+        };
+        assert_eq!(viewport.map(1.5, 0.6), (74, 30));
+    }
+}
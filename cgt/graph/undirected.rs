@@ -65,6 +65,12 @@ impl Graph {
         self.0.connect(rhs_vertex, lhs_vertex, connect);
     }
 
+    /// Connect two vertices with an edge. Shorthand for [`Self::connect`] with `true`.
+    #[inline]
+    pub fn add_edge(&mut self, lhs_vertex: usize, rhs_vertex: usize) {
+        self.connect(lhs_vertex, rhs_vertex, true);
+    }
+
     /// Get vertices adjacent to `vertex`.
     #[inline]
     pub fn adjacent_to(&self, vertex: usize) -> Vec<usize> {
@@ -137,6 +143,38 @@ impl Graph {
 
         seen.iter().all(|b| *b)
     }
+
+    /// Get vertices of each connected component, in order of their lowest vertex
+    pub fn connected_components(&self) -> Vec<Vec<usize>> {
+        let mut seen = vec![false; self.size()];
+        let mut components = Vec::new();
+
+        for start in self.vertices() {
+            if seen[start] {
+                continue;
+            }
+
+            let mut component = Vec::new();
+            let mut queue: VecDeque<usize> = VecDeque::new();
+            seen[start] = true;
+            queue.push_back(start);
+
+            while let Some(v) = queue.pop_front() {
+                component.push(v);
+                for u in self.adjacent_to(v) {
+                    if !seen[u] {
+                        seen[u] = true;
+                        queue.push_back(u);
+                    }
+                }
+            }
+
+            component.sort_unstable();
+            components.push(component);
+        }
+
+        components
+    }
 }
 
 /// ```text
@@ -179,3 +217,19 @@ fn test_adjacency() {
     assert_eq!(m.adjacent_to(2), vec![3]);
     assert_eq!(m.adjacent_to(3), vec![0, 1, 2]);
 }
+
+#[test]
+fn connected_components_works() {
+    let graph = Graph::from_edges(7, &[(0, 4), (4, 2), (1, 3), (5, 5)]);
+    assert_eq!(
+        graph.connected_components(),
+        vec![vec![0, 2, 4], vec![1, 3], vec![5], vec![6]]
+    );
+    assert!(!graph.is_connected());
+
+    let graph = test_matrix();
+    assert_eq!(graph.connected_components(), vec![vec![0, 1, 2, 3]]);
+    assert!(graph.is_connected());
+
+    assert!(Graph::empty(0).connected_components().is_empty());
+}
@@ -1,6 +1,9 @@
 //! Finite grids
 
-use std::{collections::VecDeque, fmt::Write};
+use std::{
+    collections::VecDeque,
+    fmt::{self, Display, Write},
+};
 
 pub mod small_bit_grid;
 pub mod vec_grid;
@@ -31,6 +34,139 @@ pub trait FiniteGrid: Grid + Sized {
     /// Create new zero-sized grid
     fn zero_size() -> Self;
 
+    /// Bit mask of tiles `(x, y)` such that both `(x, y)` and `(x + dx, y + dy)` are inside the
+    /// grid and satisfy `predicate`. Tile `(x, y)` is stored at bit `y * width + x`.
+    ///
+    /// Returns [None] if the grid has more than 64 tiles.
+    fn pairs_mask(&self, dx: u8, dy: u8, predicate: fn(Self::Item) -> bool) -> Option<u64> {
+        let width = self.width();
+        let height = self.height();
+        if width as usize * height as usize > u64::BITS as usize {
+            return None;
+        }
+
+        let mut mask = 0;
+        for y in 0..height.saturating_sub(dy) {
+            for x in 0..width.saturating_sub(dx) {
+                if predicate(self.get(x, y)) && predicate(self.get(x + dx, y + dy)) {
+                    mask |= 1 << (y as u32 * width as u32 + x as u32);
+                }
+            }
+        }
+        Some(mask)
+    }
+
+    /// Coordinates of tiles in each connected region of tiles satisfying `is_empty`. Tiles are
+    /// connected horizontally and vertically. Regions are ordered by their first tile in row-major
+    /// order, and tiles within a region in order of discovery.
+    fn regions(&self, is_empty: fn(Self::Item) -> bool) -> Vec<Vec<(u8, u8)>> {
+        let width = self.width();
+        let height = self.height();
+        let mut visited = vec![false; width as usize * height as usize];
+        let mut regions = Vec::new();
+
+        for y in 0..height {
+            for x in 0..width {
+                if visited[y as usize * width as usize + x as usize] || !is_empty(self.get(x, y)) {
+                    continue;
+                }
+
+                let mut region = Vec::new();
+                let mut q = VecDeque::new();
+                visited[y as usize * width as usize + x as usize] = true;
+                q.push_back((x, y));
+
+                while let Some((qx, qy)) = q.pop_front() {
+                    region.push((qx, qy));
+                    let neighbors = [
+                        (qx.checked_sub(1), Some(qy)),
+                        ((qx + 1 < width).then_some(qx + 1), Some(qy)),
+                        (Some(qx), qy.checked_sub(1)),
+                        (Some(qx), (qy + 1 < height).then_some(qy + 1)),
+                    ];
+                    for (nx, ny) in neighbors {
+                        let (Some(nx), Some(ny)) = (nx, ny) else {
+                            continue;
+                        };
+                        let idx = ny as usize * width as usize + nx as usize;
+                        if !visited[idx] && is_empty(self.get(nx, ny)) {
+                            visited[idx] = true;
+                            q.push_back((nx, ny));
+                        }
+                    }
+                }
+
+                regions.push(region);
+            }
+        }
+
+        regions
+    }
+
+    /// Flips along either axis that map the grid onto itself, as `(flip_x, flip_y)`. The first
+    /// flip is always the identity.
+    fn symmetry_flips(&self) -> Vec<(bool, bool)>
+    where
+        Self::Item: PartialEq,
+    {
+        let width = self.width();
+        let height = self.height();
+        [(false, false), (true, false), (false, true), (true, true)]
+            .into_iter()
+            .filter(|&(flip_x, flip_y)| {
+                (0..height).all(|y| {
+                    (0..width).all(|x| {
+                        let fx = if flip_x { width - x - 1 } else { x };
+                        let fy = if flip_y { height - y - 1 } else { y };
+                        self.get(x, y) == self.get(fx, fy)
+                    })
+                })
+            })
+            .collect()
+    }
+
+    /// Images of tile `(x, y)` under `flips`, see [`FiniteGrid::symmetry_flips`]. The first image
+    /// is the tile itself. Images of different tiles at the same index come from the same flip, so
+    /// sets of tiles can be mapped by zipping their orbits.
+    fn tile_orbit<'a>(
+        &self,
+        flips: &'a [(bool, bool)],
+        x: u8,
+        y: u8,
+    ) -> impl Iterator<Item = (u8, u8)> + 'a {
+        let width = self.width();
+        let height = self.height();
+        flips.iter().map(move |&(flip_x, flip_y)| {
+            (
+                if flip_x { width - x - 1 } else { x },
+                if flip_y { height - y - 1 } else { y },
+            )
+        })
+    }
+
+    /// Tiles satisfying `predicate`, one per orbit under flips that map the grid onto itself,
+    /// see [`FiniteGrid::tile_orbit`]. The representative is the first tile of the orbit in
+    /// row-major order.
+    fn symmetry_distinct_tiles(&self, predicate: impl Fn(Self::Item) -> bool) -> Vec<(u8, u8)>
+    where
+        Self::Item: PartialEq,
+    {
+        let flips = self.symmetry_flips();
+        let mut tiles = Vec::new();
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                if predicate(self.get(x, y))
+                    && self
+                        .tile_orbit(&flips, x, y)
+                        .all(|(ox, oy)| (y, x) <= (oy, ox))
+                {
+                    tiles.push((x, y));
+                }
+            }
+        }
+        tiles
+    }
+
     /// Default, one-line display function for grids using `|` as row separator
     #[cfg_attr(feature = "cargo-clippy", allow(clippy::missing_errors_doc))]
     fn display(&self, w: &mut impl Write, sep: char) -> std::fmt::Result
@@ -48,16 +184,89 @@ pub trait FiniteGrid: Grid + Sized {
         Ok(())
     }
 
-    /// Parse grid from string following notation from [`Self::display`]
-    fn parse(input: &str) -> Option<Self>
+    /// Serialize grid to a portable format independent of the internal representation. First
+    /// two bytes are width and height, followed by one byte per tile in row-major order, holding
+    /// the tile character (see [`CharTile`]).
+    fn to_portable_bytes(&self) -> Vec<u8>
+    where
+        Self::Item: CharTile,
+    {
+        let mut bytes = Vec::with_capacity(2 + self.width() as usize * self.height() as usize);
+        bytes.push(self.width());
+        bytes.push(self.height());
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                let mut buf = [0; 4];
+                let encoded = self.get(x, y).tile_to_char().encode_utf8(&mut buf);
+                debug_assert_eq!(encoded.len(), 1, "tile characters must be ASCII");
+                bytes.push(buf[0]);
+            }
+        }
+        bytes
+    }
+
+    /// Deserialize grid from format produced by [`Self::to_portable_bytes`]
+    ///
+    /// # Errors
+    /// - Number of bytes does not match the grid size
+    /// - Byte is not a valid tile character
+    /// - Grid is too large to fit in `Self`
+    fn from_portable_bytes(bytes: &[u8]) -> Option<Self>
     where
         Self::Item: CharTile + Default,
     {
-        let row_separator = '|';
-        let width = input.split(row_separator).next()?.len() as u8;
-        let height = input.chars().filter(|c| *c == row_separator).count() as u8 + 1;
+        let (&[width, height], tiles) = bytes.split_first_chunk::<2>()?;
+        if tiles.len() != width as usize * height as usize {
+            return None;
+        }
 
         let mut grid = Self::filled(width, height, Default::default())?;
+        for (idx, &byte) in tiles.iter().enumerate() {
+            let x = (idx % width as usize) as u8;
+            let y = (idx / width as usize) as u8;
+            grid.set(x, y, Self::Item::char_to_tile(byte as char)?);
+        }
+        Some(grid)
+    }
+
+    /// Parse grid from string following notation from [`Self::display`]
+    ///
+    /// Empty input is a valid grid with no tiles.
+    ///
+    /// # Errors
+    /// - Input has multiple rows without tiles, e.g. `"|"`
+    /// - Rows have different lengths
+    /// - Input contains character that is not a valid tile
+    /// - Grid is too large to fit in `Self`
+    fn parse(input: &str) -> Result<Self, GridParseError>
+    where
+        Self::Item: CharTile + Default,
+    {
+        Self::parse_with_separator(input, '|')
+    }
+
+    /// Parse grid from string with rows separated by `row_separator` instead of `|`
+    ///
+    /// # Errors
+    /// See [`FiniteGrid::parse`]
+    fn parse_with_separator(input: &str, row_separator: char) -> Result<Self, GridParseError>
+    where
+        Self::Item: CharTile + Default,
+    {
+        let width = input
+            .split(row_separator)
+            .next()
+            .map_or(0, |row| row.chars().count());
+        let height = input.chars().filter(|c| *c == row_separator).count() + 1;
+        if width == 0 && height > 1 {
+            return Err(GridParseError::Empty);
+        }
+        let (Ok(width), Ok(height)) = (u8::try_from(width), u8::try_from(height)) else {
+            return Err(GridParseError::TooLarge);
+        };
+
+        let mut grid =
+            Self::filled(width, height, Default::default()).ok_or(GridParseError::TooLarge)?;
         let mut x = 0;
         let mut y = 0;
 
@@ -68,27 +277,55 @@ pub trait FiniteGrid: Grid + Sized {
                     y += 1;
                     continue;
                 }
-                // Not a rectangle
-                return None;
+                return Err(GridParseError::NotRectangular);
             }
 
             if x >= width {
-                return None;
+                return Err(GridParseError::NotRectangular);
             }
 
-            let value = Self::Item::char_to_tile(chr)?;
+            let value = Self::Item::char_to_tile(chr).ok_or(GridParseError::InvalidChar(chr))?;
             grid.set(x, y, value);
             x += 1;
         }
 
         if x != width {
             // Not a rectangle in the last row
-            return None;
+            return Err(GridParseError::NotRectangular);
+        }
+        Ok(grid)
+    }
+}
+
+/// Error when parsing a grid, see [`FiniteGrid::parse`]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum GridParseError {
+    /// Grid does not fit in the grid type
+    TooLarge,
+
+    /// Rows have different lengths
+    NotRectangular,
+
+    /// Character is not a valid tile
+    InvalidChar(char),
+
+    /// Input has rows, but none of them has tiles
+    Empty,
+}
+
+impl Display for GridParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooLarge => write!(f, "Grid is too large"),
+            Self::NotRectangular => write!(f, "Grid is not rectangular"),
+            Self::InvalidChar(chr) => write!(f, "Invalid tile '{chr}'"),
+            Self::Empty => write!(f, "Grid is empty"),
         }
-        Some(grid)
     }
 }
 
+impl std::error::Error for GridParseError {}
+
 /// Grid tiles that are representable as a single character, other than `'|'`
 pub trait CharTile: Sized {
     /// Convert tile to `char`
@@ -1,11 +1,36 @@
 //! Grid with up to 64 tiles holding a single bit of information.
 
-use crate::grid::{BitTile, CharTile, FiniteGrid, Grid};
-use std::{fmt::Display, marker::PhantomData, str::FromStr};
+use crate::grid::{BitTile, CharTile, FiniteGrid, Grid, GridParseError};
+use std::{
+    fmt::{self, Display},
+    marker::PhantomData,
+    str::FromStr,
+};
 
 /// Internal representation of a grid
 type GridBits = u64;
 
+/// Error when creating a grid, see [`SmallBitGrid::from_number_checked`]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum FromNumberError {
+    /// Grid has more than 64 tiles
+    TooLarge,
+
+    /// Bits outside grid size are set, holds the offending bits
+    OutOfRangeBits(GridBits),
+}
+
+impl Display for FromNumberError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooLarge => write!(f, "Grid is too large"),
+            Self::OutOfRangeBits(bits) => write!(f, "Bits outside grid are set: {bits:#b}"),
+        }
+    }
+}
+
+impl std::error::Error for FromNumberError {}
+
 /// A grid with up to 64 tiles holding a single bit of information.
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -70,6 +95,35 @@ where
             _ty: PhantomData,
         }
     }
+
+    /// Computed with bitwise operations on the whole grid instead of checking tiles one by one
+    fn pairs_mask(&self, dx: u8, dy: u8, predicate: fn(Self::Item) -> bool) -> Option<u64> {
+        let tiles = self.width as u32 * self.height as u32;
+        let in_grid = GridBits::MAX
+            .checked_shr(GridBits::BITS - tiles)
+            .unwrap_or(0);
+
+        let mut matching = 0;
+        if predicate(T::bool_to_tile(true)) {
+            matching |= self.grid;
+        }
+        if predicate(T::bool_to_tile(false)) {
+            matching |= !self.grid;
+        }
+        matching &= in_grid;
+
+        // Tiles whose pair is still inside the grid
+        let row = GridBits::MAX
+            .checked_shr(GridBits::BITS - self.width.saturating_sub(dx) as u32)
+            .unwrap_or(0);
+        let mut valid = 0;
+        for y in 0..self.height.saturating_sub(dy) {
+            valid |= row << (y as u32 * self.width as u32);
+        }
+
+        let offset = dy as u32 * self.width as u32 + dx as u32;
+        Some(matching & matching.checked_shr(offset).unwrap_or(0) & valid)
+    }
 }
 
 impl<T> Display for SmallBitGrid<T>
@@ -144,6 +198,39 @@ where
         })
     }
 
+    /// Like [`SmallBitGrid::from_number`], but bits outside grid size are reported instead of
+    /// being ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cgt::grid::small_bit_grid::{FromNumberError, SmallBitGrid};
+    ///
+    /// assert!(SmallBitGrid::<bool>::from_number_checked(2, 2, 0b1001).is_ok());
+    /// assert_eq!(
+    ///     SmallBitGrid::<bool>::from_number_checked(2, 2, 0b110000),
+    ///     Err(FromNumberError::OutOfRangeBits(0b110000))
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    /// - Grid has more than 64 tiles
+    /// - Any bit outside grid size is set
+    pub fn from_number_checked(
+        width: u8,
+        height: u8,
+        grid_id: GridBits,
+    ) -> Result<Self, FromNumberError> {
+        Self::check_dimensions(width, height).ok_or(FromNumberError::TooLarge)?;
+        let out_of_range = grid_id
+            .checked_shr(width as u32 * height as u32)
+            .map_or(0, |high| high << (width as u32 * height as u32));
+        if out_of_range != 0 {
+            return Err(FromNumberError::OutOfRangeBits(out_of_range));
+        }
+        Self::from_number(width, height, grid_id).ok_or(FromNumberError::TooLarge)
+    }
+
     /// Creates a grid from given array of bools.
     ///
     /// # Arguments
@@ -164,6 +251,61 @@ where
         Self::from_number(width, height, arr_to_bits(grid))
     }
 
+    /// Add rows and columns of filled (`true` bit) tiles around the grid. Inverse of
+    /// [`move_top_left`](crate::grid::move_top_left) for grids without filled edges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cgt::grid::small_bit_grid::SmallBitGrid;
+    /// use std::str::FromStr;
+    ///
+    /// let grid = SmallBitGrid::<bool>::from_str("..|.#").unwrap();
+    /// assert_eq!(&format!("{}", grid.pad(1, 0, 0, 1).unwrap()), "###|..#|.##");
+    /// ```
+    ///
+    /// # Errors
+    /// - Padded grid has more than 64 tiles
+    pub fn pad(&self, top: u8, bottom: u8, left: u8, right: u8) -> Option<Self> {
+        let width = self.width.checked_add(left)?.checked_add(right)?;
+        let height = self.height.checked_add(top)?.checked_add(bottom)?;
+        let mut result = Self::filled(width, height, T::bool_to_tile(true))?;
+        for y in 0..self.height {
+            for x in 0..self.width {
+                result.set(x + left, y + top, self.get(x, y));
+            }
+        }
+        Some(result)
+    }
+
+    /// Extract `width` by `height` subgrid with top left corner at `(x, y)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cgt::grid::small_bit_grid::SmallBitGrid;
+    /// use std::str::FromStr;
+    ///
+    /// let grid = SmallBitGrid::<bool>::from_str("#..|.#.|..#").unwrap();
+    /// assert_eq!(&format!("{}", grid.crop(1, 1, 2, 2).unwrap()), "#.|.#");
+    /// ```
+    ///
+    /// # Errors
+    /// - Subgrid does not fit in the grid
+    pub fn crop(&self, x: u8, y: u8, width: u8, height: u8) -> Option<Self> {
+        if x.checked_add(width)? > self.width || y.checked_add(height)? > self.height {
+            return None;
+        }
+
+        let mut result = Self::empty(width, height)?;
+        for dy in 0..height {
+            for dx in 0..width {
+                result.set(dx, dy, self.get(x + dx, y + dy));
+            }
+        }
+        Some(result)
+    }
+
     /// Rotate grid 90° clockwise
     #[must_use]
     #[cfg_attr(feature = "cargo-clippy", allow(clippy::missing_panics_doc))]
@@ -212,10 +354,10 @@ impl<T> FromStr for SmallBitGrid<T>
 where
     T: BitTile + CharTile + Default,
 {
-    type Err = ();
+    type Err = GridParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Self::parse(s).ok_or(())
+        Self::parse(s)
     }
 }
 
@@ -250,6 +392,7 @@ pub fn bits_to_arr(num: GridBits) -> [bool; 64] {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::grid::vec_grid::VecGrid;
 
     #[test]
     fn set_works() {
@@ -296,22 +439,117 @@ mod tests {
         );
     }
 
-    #[should_panic]
     #[test]
     fn parse_invalid_char() {
-        SmallBitGrid::<bool>::from_str("...#|..X#|.#..").unwrap();
+        assert_eq!(
+            SmallBitGrid::<bool>::from_str("...#|..X#|.#.."),
+            Err(GridParseError::InvalidChar('X'))
+        );
     }
 
-    #[should_panic]
     #[test]
     fn parse_non_rectangular() {
-        SmallBitGrid::<bool>::from_str("...#|..#|.#..").unwrap();
+        assert_eq!(
+            SmallBitGrid::<bool>::from_str("...#|..#|.#.."),
+            Err(GridParseError::NotRectangular)
+        );
     }
 
-    #[should_panic]
     #[test]
     fn parse_non_rectangular_last() {
-        SmallBitGrid::<bool>::from_str("...#|..#.|.#.").unwrap();
+        assert_eq!(
+            SmallBitGrid::<bool>::from_str("...#|..#.|.#."),
+            Err(GridParseError::NotRectangular)
+        );
+    }
+
+    #[test]
+    fn parse_too_large() {
+        assert_eq!(
+            SmallBitGrid::<bool>::from_str(
+                "........|........|........|........|........|........|........|........|........"
+            ),
+            Err(GridParseError::TooLarge)
+        );
+        assert_eq!(
+            VecGrid::<bool>::parse(&".".repeat(256)),
+            Err(GridParseError::TooLarge)
+        );
+    }
+
+    #[test]
+    fn parse_empty() {
+        assert_eq!(
+            SmallBitGrid::<bool>::from_str(""),
+            Ok(SmallBitGrid::empty(0, 1).unwrap())
+        );
+        assert_eq!(
+            SmallBitGrid::<bool>::from_str("|"),
+            Err(GridParseError::Empty)
+        );
+        assert_eq!(
+            SmallBitGrid::<bool>::from_str("||"),
+            Err(GridParseError::Empty)
+        );
+    }
+
+    #[test]
+    fn from_number_checked_reports_ignored_bits() {
+        assert_eq!(
+            SmallBitGrid::<bool>::from_number_checked(2, 2, 0b10000),
+            Err(FromNumberError::OutOfRangeBits(0b10000))
+        );
+        assert_eq!(
+            SmallBitGrid::<bool>::from_number_checked(2, 2, 0b1111),
+            Ok(SmallBitGrid::from_number(2, 2, 0b1111).unwrap())
+        );
+        assert_eq!(
+            SmallBitGrid::<bool>::from_number_checked(8, 8, u64::MAX),
+            Ok(SmallBitGrid::from_number(8, 8, u64::MAX).unwrap())
+        );
+        assert_eq!(
+            SmallBitGrid::<bool>::from_number_checked(9, 8, 0),
+            Err(FromNumberError::TooLarge)
+        );
+    }
+
+    #[test]
+    fn portable_bytes_roundtrip() {
+        let grid = SmallBitGrid::<bool>::parse("..#|#.#").unwrap();
+        let bytes = grid.to_portable_bytes();
+        assert_eq!(bytes, b"\x03\x02..##.#");
+        assert_eq!(SmallBitGrid::from_portable_bytes(&bytes), Some(grid));
+
+        assert_eq!(
+            SmallBitGrid::<bool>::from_portable_bytes(b"\x03\x02..##."),
+            None
+        );
+        assert_eq!(
+            SmallBitGrid::<bool>::from_portable_bytes(b"\x01\x01x"),
+            None
+        );
+        assert_eq!(SmallBitGrid::<bool>::from_portable_bytes(b"\x01"), None);
+    }
+
+    #[test]
+    fn pad_move_top_left_roundtrip() {
+        let grid = SmallBitGrid::<bool>::from_str("..#|#..").unwrap();
+        for (top, bottom, left, right) in [(0, 0, 0, 0), (1, 0, 0, 0), (2, 1, 3, 1), (0, 3, 0, 2)] {
+            let padded = grid.pad(top, bottom, left, right).unwrap();
+            assert_eq!(padded.width(), grid.width() + left + right);
+            assert_eq!(padded.height(), grid.height() + top + bottom);
+            assert_eq!(
+                crate::grid::move_top_left(&padded, |tile: bool| !tile),
+                grid
+            );
+            assert_eq!(
+                padded.crop(left, top, grid.width(), grid.height()),
+                Some(grid)
+            );
+        }
+
+        assert_eq!(grid.pad(10, 10, 0, 0), None);
+        assert_eq!(grid.crop(1, 0, 3, 2), None);
     }
 
     #[test]
@@ -364,4 +602,31 @@ mod tests {
 	 ##..",
         );
     }
+
+    #[test]
+    fn symmetry_distinct_tiles_works() {
+        let empty = SmallBitGrid::<bool>::parse("...|...").unwrap();
+        let flips = empty.symmetry_flips();
+        assert_eq!(flips.len(), 4);
+        assert_eq!(
+            empty.tile_orbit(&flips, 0, 0).collect::<Vec<_>>(),
+            vec![(0, 0), (2, 0), (0, 1), (2, 1)]
+        );
+        assert_eq!(
+            empty.symmetry_distinct_tiles(|tile| !tile),
+            vec![(0, 0), (1, 0)]
+        );
+
+        let mirrored = SmallBitGrid::<bool>::parse("#.#|...").unwrap();
+        let flips = mirrored.symmetry_flips();
+        assert_eq!(flips, vec![(false, false), (true, false)]);
+        assert_eq!(
+            mirrored.tile_orbit(&flips, 0, 1).collect::<Vec<_>>(),
+            vec![(0, 1), (2, 1)]
+        );
+        assert_eq!(
+            mirrored.symmetry_distinct_tiles(|tile| !tile),
+            vec![(1, 0), (0, 1), (1, 1)]
+        );
+    }
 }
@@ -11,6 +11,37 @@ pub struct VecGrid<T> {
     grid: Vec<T>,
 }
 
+impl<T> VecGrid<T>
+where
+    T: Clone,
+{
+    /// Create grid from rows of tiles, top to bottom
+    ///
+    /// # Errors
+    /// - Rows have different lengths
+    /// - Grid is larger than 255 tiles in any direction
+    pub fn from_rows(rows: &[Vec<T>]) -> Option<Self> {
+        let height = u8::try_from(rows.len()).ok()?;
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        let width = u8::try_from(width).ok()?;
+
+        Some(Self {
+            width,
+            height,
+            grid: rows.concat(),
+        })
+    }
+
+    /// Iterate over rows of tiles, top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        let width = self.width as usize;
+        (0..self.height as usize).map(move |y| &self.grid[width * y..width * (y + 1)])
+    }
+}
+
 impl<T> Grid for VecGrid<T>
 where
     T: Clone,
@@ -55,3 +86,32 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rows_roundtrip() {
+        let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+        let grid = VecGrid::from_rows(&rows).unwrap();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.get(2, 0), 3);
+        assert_eq!(grid.get(0, 1), 4);
+        assert_eq!(grid.rows().map(<[i32]>::to_vec).collect::<Vec<_>>(), rows);
+
+        assert_eq!(VecGrid::from_rows(&[vec![1, 2], vec![3]]), None);
+        assert_eq!(VecGrid::<i32>::from_rows(&[]), Some(VecGrid::zero_size()));
+    }
+
+    #[test]
+    fn portable_bytes_roundtrip() {
+        use crate::short::partizan::games::domineering::Tile;
+
+        let grid = VecGrid::<Tile>::parse(".#|..|#.").unwrap();
+        let bytes = grid.to_portable_bytes();
+        assert_eq!(bytes, b"\x02\x03.#..#.");
+        assert_eq!(VecGrid::from_portable_bytes(&bytes), Some(grid));
+    }
+}
@@ -10,6 +10,17 @@ use std::{
     ops::{Add, Sub},
 };
 
+/// Single step in the sign expansion of a number
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Sign {
+    /// Step towards positive numbers, i.e. Left option of the next number
+    Plus,
+
+    /// Step towards negative numbers, i.e. Right option of the next number
+    Minus,
+}
+
 /// Number in form `n/2^m`
 #[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -128,6 +139,30 @@ impl DyadicRationalNumber {
                 .expect("unreachable: denominator cannot be zero") as i64
     }
 
+    /// Absolute value
+    ///
+    /// # Panics
+    /// - If numerator is [`i64::MIN`], see [`DyadicRationalNumber::checked_abs`]
+    #[must_use]
+    pub const fn abs(self) -> Self {
+        match self.checked_abs() {
+            Some(abs) => abs,
+            None => panic!("DyadicRationalNumber::abs: numerator overflow"),
+        }
+    }
+
+    /// Absolute value, or [None] if numerator is [`i64::MIN`]
+    #[must_use]
+    pub const fn checked_abs(self) -> Option<Self> {
+        match self.numerator.checked_abs() {
+            Some(numerator) => Some(Self {
+                numerator,
+                denominator_exponent: self.denominator_exponent,
+            }),
+            None => None,
+        }
+    }
+
     /// Arithmetic mean of two rationals
     #[must_use]
     pub fn mean(&self, rhs: &Self) -> Self {
@@ -165,6 +200,79 @@ impl DyadicRationalNumber {
         Self::new_fraction(numerator, denominator)
     }
 
+    /// Get the sign expansion of the number, i.e. the path from `0` to the number in the surreal
+    /// construction where each step moves to the simplest number on the given side.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cgt::numeric::dyadic_rational_number::{DyadicRationalNumber, Sign};
+    ///
+    /// assert_eq!(
+    ///     DyadicRationalNumber::new(3, 3).simplest_form_path(),
+    ///     vec![Sign::Plus, Sign::Minus, Sign::Minus, Sign::Plus]
+    /// );
+    /// ```
+    pub fn simplest_form_path(&self) -> Vec<Sign> {
+        let zero = Self::from(0);
+        let (direction, unit) = match self.cmp(&zero) {
+            std::cmp::Ordering::Equal => return Vec::new(),
+            std::cmp::Ordering::Greater => (Sign::Plus, Self::from(1)),
+            std::cmp::Ordering::Less => (Sign::Minus, Self::from(-1)),
+        };
+
+        let mut path = Vec::new();
+        let mut current = zero;
+
+        // Integer steps until we hit or jump over the number
+        while (direction == Sign::Plus && current < *self)
+            || (direction == Sign::Minus && current > *self)
+        {
+            current += unit;
+            path.push(direction);
+        }
+
+        // Binary search steps with halving step size
+        let mut exponent = 1;
+        while current != *self {
+            let step = Self::new(1, exponent);
+            if *self > current {
+                current += step;
+                path.push(Sign::Plus);
+            } else {
+                current -= step;
+                path.push(Sign::Minus);
+            }
+            exponent += 1;
+        }
+
+        path
+    }
+
+    /// Construct number from its sign expansion. Inverse of [`Self::simplest_form_path`].
+    pub fn from_sign_sequence(signs: &[Sign]) -> Self {
+        let mut current = Self::from(0);
+        let Some(direction) = signs.first().copied() else {
+            return current;
+        };
+
+        let integer_steps = signs.iter().take_while(|sign| **sign == direction).count();
+        current += match direction {
+            Sign::Plus => Self::from(integer_steps as i64),
+            Sign::Minus => Self::from(-(integer_steps as i64)),
+        };
+
+        for (exponent, sign) in (1..).zip(&signs[integer_steps..]) {
+            let step = Self::new(1, exponent);
+            match sign {
+                Sign::Plus => current += step,
+                Sign::Minus => current -= step,
+            }
+        }
+
+        current
+    }
+
     /// Convert dyadic to rational
     ///
     /// # Panics
@@ -199,6 +307,12 @@ impl From<i64> for DyadicRationalNumber {
     }
 }
 
+impl From<i32> for DyadicRationalNumber {
+    fn from(value: i32) -> Self {
+        Self::from(value as i64)
+    }
+}
+
 impl PartialOrd for DyadicRationalNumber {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         Some(self.cmp(other))
@@ -271,15 +385,16 @@ impl_op_ex!(-|lhs: &DyadicRationalNumber| -> DyadicRationalNumber {
 });
 
 impl Display for DyadicRationalNumber {
+    /// Supports width, fill, and alignment flags, e.g. `{:>8}`
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if let Some(int) = self.to_integer() {
-            write!(f, "{}", int)?;
+        let formatted = if let Some(int) = self.to_integer() {
+            format!("{}", int)
         } else if let Some(denum) = self.denominator() {
-            write!(f, "{}/{}", self.numerator(), denum)?;
+            format!("{}/{}", self.numerator(), denum)
         } else {
-            write!(f, "{}/2^{}", self.numerator(), self.denominator_exponent())?;
-        }
-        Ok(())
+            format!("{}/2^{}", self.numerator(), self.denominator_exponent())
+        };
+        f.pad(&formatted)
     }
 }
 
@@ -296,6 +411,24 @@ mod tests {
         assert_eq!(half + one, DyadicRationalNumber::new(3, 1));
     }
 
+    #[test]
+    fn negation_and_comparison() {
+        let half = DyadicRationalNumber::new(1, 1);
+        let three_quarters = DyadicRationalNumber::new(3, 2);
+        let eighth = DyadicRationalNumber::new(1, 3);
+        assert!(half < three_quarters);
+        assert!(-half > -three_quarters);
+        assert_eq!(-eighth, DyadicRationalNumber::from(0) - eighth);
+        assert_eq!(three_quarters - half, DyadicRationalNumber::new(1, 2));
+        assert_eq!((-eighth).abs(), eighth);
+        assert_eq!(eighth.abs(), eighth);
+        assert_eq!((-eighth).checked_abs(), Some(eighth));
+        assert_eq!(
+            DyadicRationalNumber::new_integer(i64::MIN).checked_abs(),
+            None
+        );
+    }
+
     #[test]
     fn denominator_works() {
         assert_eq!(
@@ -326,6 +459,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn display_works() {
+        assert_eq!(format!("{}", DyadicRationalNumber::new(1, 3)), "1/8");
+        assert_eq!(format!("{}", DyadicRationalNumber::from(-3)), "-3");
+        assert_eq!(format!("{}", DyadicRationalNumber::from(0)), "0");
+        assert_eq!(format!("{}", -DyadicRationalNumber::new(3, 2)), "-3/4");
+        assert_eq!(format!("{:>5}", DyadicRationalNumber::new(1, 3)), "  1/8");
+        assert_eq!(format!("{:<4}|", DyadicRationalNumber::from(-3)), "-3  |");
+    }
+
     #[cfg(test)]
     fn test_parsing_works(inp: &str) {
         let number = DyadicRationalNumber::from_str(inp).unwrap();
@@ -339,6 +482,35 @@ mod tests {
         test_parsing_works("-1/2");
     }
 
+    #[test]
+    fn sign_sequence_roundtrip() {
+        use Sign::{Minus, Plus};
+
+        assert_eq!(DyadicRationalNumber::from(0).simplest_form_path(), vec![]);
+        assert_eq!(
+            DyadicRationalNumber::from(-2).simplest_form_path(),
+            vec![Minus, Minus]
+        );
+        assert_eq!(
+            DyadicRationalNumber::new(5, 1).simplest_form_path(),
+            vec![Plus, Plus, Plus, Minus]
+        );
+        assert_eq!(
+            DyadicRationalNumber::new(-3, 2).simplest_form_path(),
+            vec![Minus, Plus, Minus]
+        );
+
+        for numerator in -40..=40 {
+            for exponent in 0..4 {
+                let number = DyadicRationalNumber::new(numerator, exponent);
+                assert_eq!(
+                    DyadicRationalNumber::from_sign_sequence(&number.simplest_form_path()),
+                    number
+                );
+            }
+        }
+    }
+
     #[test]
     #[should_panic]
     fn parsing_works_negative() {
@@ -22,6 +22,42 @@ impl Nimber {
         self.0
     }
 
+    /// Split nimber into powers of two, in increasing order. Nimber is the nim sum of its binary
+    /// components, e.g. `*5 = *1 + *4`.
+    pub fn binary_components(&self) -> Vec<Self> {
+        (0..u32::BITS)
+            .map(|bit| 1 << bit)
+            .filter(|power| self.0 & power != 0)
+            .map(Self)
+            .collect()
+    }
+
+    /// Nim multiplication, i.e. multiplication in the field of nimbers
+    #[must_use]
+    pub const fn nim_mul(self, rhs: Self) -> Self {
+        Self(Self::nim_mul_bits(self.0, rhs.0, 32))
+    }
+
+    /// Multiply `lhs` and `rhs` that are smaller than `2^bits`, where `bits` is a power of two.
+    /// Uses the fact that for Fermat 2-power `F`, `F⊗F = F⊕F/2`.
+    const fn nim_mul_bits(lhs: u32, rhs: u32, bits: u32) -> u32 {
+        if bits == 1 {
+            return lhs & rhs;
+        }
+
+        let half = bits / 2;
+        let mask = (1 << half) - 1;
+        let (lhs_high, lhs_low) = (lhs >> half, lhs & mask);
+        let (rhs_high, rhs_low) = (rhs >> half, rhs & mask);
+
+        let low = Self::nim_mul_bits(lhs_low, rhs_low, half);
+        let mixed = Self::nim_mul_bits(lhs_high ^ lhs_low, rhs_high ^ rhs_low, half);
+        let high = Self::nim_mul_bits(lhs_high, rhs_high, half);
+        let high_half = Self::nim_mul_bits(high, 1 << (half - 1), half);
+
+        ((mixed ^ low) << half) | (low ^ high_half)
+    }
+
     /// Compute the minimum excluded value from a vector of nimbers.
     /// See <https://en.wikipedia.org/wiki/Mex_(mathematics)>
     pub fn mex(mut nimbers: Vec<Self>) -> Self {
@@ -86,3 +122,43 @@ fn mex_works() {
 
     assert_eq!(Nimber(0), Nimber::mex(vec![]));
 }
+
+#[test]
+fn binary_components_works() {
+    assert_eq!(Nimber(5).binary_components(), vec![Nimber(1), Nimber(4)]);
+    assert_eq!(Nimber(0).binary_components(), vec![]);
+
+    for value in [0, 1, 6, 13, 64, u32::MAX] {
+        let nimber = Nimber(value);
+        let sum = nimber
+            .binary_components()
+            .into_iter()
+            .fold(Nimber(0), |acc, component| acc + component);
+        assert_eq!(sum, nimber);
+    }
+}
+
+#[test]
+fn nim_mul_works() {
+    let mul = |lhs, rhs| Nimber(lhs).nim_mul(Nimber(rhs)).value();
+
+    assert_eq!(mul(0, 5), 0);
+    assert_eq!(mul(1, 5), 5);
+    assert_eq!(mul(2, 2), 3);
+    assert_eq!(mul(2, 3), 1);
+    assert_eq!(mul(3, 3), 2);
+    assert_eq!(mul(4, 4), 6);
+    assert_eq!(mul(8, 8), 13);
+    assert_eq!(mul(16, 16), 24);
+    assert_eq!(mul(5, 7), 13);
+
+    for a in 0..20 {
+        for b in 0..20 {
+            assert_eq!(mul(a, b), mul(b, a));
+            for c in 0..20 {
+                assert_eq!(mul(a, b ^ c), mul(a, b) ^ mul(a, c));
+                assert_eq!(mul(mul(a, b), c), mul(a, mul(b, c)));
+            }
+        }
+    }
+}
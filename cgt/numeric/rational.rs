@@ -12,6 +12,8 @@ use std::{
 use std::str::FromStr;
 
 /// Infinite rational number.
+///
+/// Adding infinities of opposite signs panics, as the result is undefined.
 #[derive(Debug, Hash, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Rational {
@@ -38,9 +40,52 @@ impl Rational {
         !matches!(self, Self::Value(_))
     }
 
-    // TODO: Handle infinities
+    /// Render rational like [`Display`], but using only ASCII characters, i.e. infinities are
+    /// rendered as `inf` and `-inf`
+    pub fn to_ascii_string(&self) -> String {
+        match self {
+            Self::NegativeInfinity => "-inf".to_owned(),
+            Self::Value(val) => val.to_string(),
+            Self::PositiveInfinity => "inf".to_owned(),
+        }
+    }
+
+    /// Parse rational in form `n`, `n/d`, `∞`, `-∞`, `inf`, or `-inf`, optionally preceded by
+    /// `+`. Minus sign can be either ASCII `-` or unicode `−`, and whitespace is allowed around
+    /// every token.
     fn parse(input: &str) -> nom::IResult<&str, Self> {
-        let (input, numerator) = nom_utils::lexeme(nom::character::complete::i64)(input)?;
+        let (input, sign) = nom::combinator::opt(nom_utils::lexeme(nom::branch::alt((
+            nom::character::complete::char('+'),
+            nom::character::complete::char('-'),
+            nom::character::complete::char('−'),
+        ))))(input)?;
+        let negative = matches!(sign, Some('-' | '−'));
+
+        if let Ok((input, _)) = nom_utils::lexeme(nom::branch::alt((
+            nom::bytes::complete::tag::<&str, &str, ()>("∞"),
+            nom::bytes::complete::tag("inf"),
+        )))(input)
+        {
+            let infinity = if negative {
+                Self::NegativeInfinity
+            } else {
+                Self::PositiveInfinity
+            };
+            return Ok((input, infinity));
+        }
+
+        let (input, numerator) = nom::combinator::map_res(
+            nom_utils::lexeme(nom::character::complete::u64),
+            |magnitude| {
+                // Negate the magnitude rather than the parsed value, so `i64::MIN` fits
+                if negative {
+                    0i64.checked_sub_unsigned(magnitude)
+                } else {
+                    i64::try_from(magnitude).ok()
+                }
+                .ok_or(nom::error::ErrorKind::TooLarge)
+            },
+        )(input)?;
         match nom_utils::lexeme(nom::bytes::complete::tag::<&str, &str, ()>("/"))(input) {
             Ok((input, _)) => {
                 let (input, denominator) = nom_utils::lexeme(nom::character::complete::u32)(input)?;
@@ -101,12 +146,13 @@ impl From<i32> for Rational {
 impl_op_ex!(+|lhs: &Rational, rhs: &Rational| -> Rational {
     match (lhs, rhs) {
         (Rational::Value(lhs), Rational::Value(rhs)) => Rational::from(lhs + rhs),
-        (Rational::Value(_), Rational::PositiveInfinity) |
+        (Rational::Value(_) | Rational::PositiveInfinity, Rational::PositiveInfinity) |
         (Rational::PositiveInfinity, Rational::Value(_)) => Rational::PositiveInfinity,
-        (Rational::Value(_), Rational::NegativeInfinity) |
+        (Rational::Value(_) | Rational::NegativeInfinity, Rational::NegativeInfinity) |
         (Rational::NegativeInfinity, Rational::Value(_)) => Rational::NegativeInfinity,
-        _ => {
-            panic!()
+        (Rational::PositiveInfinity, Rational::NegativeInfinity) |
+        (Rational::NegativeInfinity, Rational::PositiveInfinity) => {
+            panic!("Sum of infinities with opposite signs is undefined: {lhs} + {rhs}")
         }
     }
 });
@@ -189,3 +235,104 @@ fn parsing_works_positive() {
     test_parsing_works("-1/2");
     test_parsing_works("2/3");
 }
+
+#[test]
+fn min_max_clamp_order_infinities() {
+    let three = Rational::from(3);
+    assert_eq!(Rational::PositiveInfinity.min(three), three);
+    assert_eq!(
+        Rational::NegativeInfinity.min(three),
+        Rational::NegativeInfinity
+    );
+    assert_eq!(
+        Rational::PositiveInfinity.max(three),
+        Rational::PositiveInfinity
+    );
+    assert_eq!(Rational::NegativeInfinity.max(three), three);
+    assert_eq!(
+        Rational::PositiveInfinity.clamp(Rational::from(-1), three),
+        three
+    );
+    assert_eq!(
+        Rational::NegativeInfinity.clamp(Rational::from(-1), three),
+        Rational::from(-1)
+    );
+    assert_eq!(
+        Rational::new(1, 2).clamp(Rational::NegativeInfinity, Rational::PositiveInfinity),
+        Rational::new(1, 2)
+    );
+}
+
+#[test]
+fn adding_infinities() {
+    let one = Rational::from(1);
+    let inf = Rational::PositiveInfinity;
+    let neg_inf = Rational::NegativeInfinity;
+
+    assert_eq!(one + inf, inf);
+    assert_eq!(inf + one, inf);
+    assert_eq!(inf + inf, inf);
+    assert_eq!(one + neg_inf, neg_inf);
+    assert_eq!(neg_inf + one, neg_inf);
+    assert_eq!(neg_inf + neg_inf, neg_inf);
+
+    for (lhs, rhs) in [(inf, neg_inf), (neg_inf, inf)] {
+        let result = std::panic::catch_unwind(|| lhs + rhs);
+        assert!(result.is_err());
+    }
+}
+
+#[test]
+fn parsing_works_lenient() {
+    assert_eq!(Rational::from_str("− 1 / 2"), Ok(Rational::new(-1, 2)));
+    assert_eq!(Rational::from_str("-1/2"), Ok(Rational::new(-1, 2)));
+    assert_eq!(Rational::from_str(" 3 / 4 "), Ok(Rational::new(3, 4)));
+    assert_eq!(Rational::from_str("−7"), Ok(Rational::from(-7)));
+    assert_eq!(Rational::from_str("∞"), Ok(Rational::PositiveInfinity));
+    assert_eq!(Rational::from_str("-∞"), Ok(Rational::NegativeInfinity));
+    assert_eq!(Rational::from_str("− ∞"), Ok(Rational::NegativeInfinity));
+    assert!(Rational::from_str("--1").is_err());
+    assert!(Rational::from_str("1/0").is_err());
+}
+
+#[test]
+fn parsing_explicit_plus() {
+    assert_eq!(Rational::from_str("+3/4"), Ok(Rational::new(3, 4)));
+    assert_eq!(Rational::from_str("+ 7"), Ok(Rational::from(7)));
+    assert_eq!(Rational::from_str("+∞"), Ok(Rational::PositiveInfinity));
+    assert!(Rational::from_str("+-1").is_err());
+}
+
+#[test]
+fn parsing_extreme_numerators() {
+    assert_eq!(
+        Rational::from_str("-9223372036854775808"),
+        Ok(Rational::from(i64::MIN))
+    );
+    assert_eq!(
+        Rational::from_str("9223372036854775807"),
+        Ok(Rational::from(i64::MAX))
+    );
+    assert!(Rational::from_str("9223372036854775808").is_err());
+    assert!(Rational::from_str("-9223372036854775809").is_err());
+}
+
+#[test]
+fn parsing_display_roundtrip_infinities() {
+    test_parsing_works("∞");
+    test_parsing_works("-∞");
+}
+
+#[test]
+fn ascii_string_works() {
+    for (rational, unicode, ascii) in [
+        (Rational::NegativeInfinity, "-∞", "-inf"),
+        (Rational::new(-3, 4), "-3/4", "-3/4"),
+        (Rational::from(2), "2", "2"),
+        (Rational::PositiveInfinity, "∞", "inf"),
+    ] {
+        assert_eq!(rational.to_string(), unicode);
+        assert_eq!(rational.to_ascii_string(), ascii);
+        assert_eq!(Rational::from_str(ascii), Ok(rational));
+    }
+}
@@ -2,3 +2,4 @@
 
 pub mod games;
 pub mod impartial_game;
+pub mod mex_table;
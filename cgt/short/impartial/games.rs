@@ -1,5 +1,8 @@
 //! Short impartial games
 
+pub mod nim;
 pub mod pseudo_quicksort;
 pub mod quicksort;
+pub mod sorting;
 pub mod subtraction;
+pub mod wythoff;
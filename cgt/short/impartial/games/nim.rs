@@ -0,0 +1,102 @@
+//! Nim is played on heaps of tokens. In each turn a player removes any positive number of tokens
+//! from a single heap.
+
+use std::fmt::Display;
+
+use crate::{
+    display,
+    short::impartial::impartial_game::{Enumerable, ImpartialGame},
+};
+
+/// Position of a [nim](self) game
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Nim {
+    // Invariant: sorted, without empty heaps
+    heaps: Vec<u32>,
+}
+
+impl Display for Nim {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Nim")?;
+        display::brackets(f, |f| display::commas(f, self.heaps()))
+    }
+}
+
+impl Nim {
+    /// Create new nim position with given heap sizes
+    #[inline]
+    pub fn new(mut heaps: Vec<u32>) -> Self {
+        heaps.retain(|heap| *heap != 0);
+        heaps.sort_unstable();
+        Self { heaps }
+    }
+
+    /// Get sizes of non-empty heaps in increasing order
+    #[inline]
+    pub const fn heaps(&self) -> &Vec<u32> {
+        &self.heaps
+    }
+}
+
+impl ImpartialGame for Nim {
+    fn moves(&self) -> Vec<Self> {
+        let mut moves = Vec::new();
+        for (idx, heap) in self.heaps.iter().enumerate() {
+            // Heaps of the same size lead to the same positions
+            if idx > 0 && self.heaps[idx - 1] == *heap {
+                continue;
+            }
+            for new_heap in 0..*heap {
+                let mut heaps = self.heaps.clone();
+                heaps[idx] = new_heap;
+                moves.push(Self::new(heaps));
+            }
+        }
+        moves
+    }
+}
+
+impl Enumerable for Nim {
+    /// All positions with at most `bound` tokens in total
+    fn positions_up_to(bound: u32) -> Vec<Self> {
+        fn partitions(remaining: u32, largest: u32, heaps: &mut Vec<u32>, result: &mut Vec<Nim>) {
+            result.push(Nim::new(heaps.clone()));
+            for heap in 1..=remaining.min(largest) {
+                heaps.push(heap);
+                partitions(remaining - heap, heap, heaps, result);
+                heaps.pop();
+            }
+        }
+
+        let mut result = Vec::new();
+        partitions(bound, bound, &mut Vec::new(), &mut result);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{numeric::nimber::Nimber, short::impartial::impartial_game::impartial_value_table};
+
+    #[test]
+    fn value_table_matches_xor() {
+        let table = impartial_value_table::<Nim>(6);
+        // Number of partitions of 0, 1, ..., 6
+        assert_eq!(table.len(), 1 + 1 + 2 + 3 + 5 + 7 + 11);
+
+        for (position, value) in &table {
+            let xor = position
+                .heaps()
+                .iter()
+                .fold(Nimber::new(0), |acc, heap| acc + Nimber::new(*heap));
+            assert_eq!(*value, xor, "{position}");
+        }
+
+        for heaps in [vec![1, 2, 3], vec![3, 3], vec![2, 2, 1, 1]] {
+            assert_eq!(table[&Nim::new(heaps)], Nimber::new(0));
+        }
+        assert_eq!(table[&Nim::new(vec![1, 3])], Nimber::new(2));
+    }
+}
@@ -4,11 +4,19 @@
 //! This is impartial version of the game, where both players can pick both even and odd pivots.
 //! For partizan version see TODO.
 //!
+//! This is [`SortingGame`] with [`QuicksortPivot`] operation.
+//!
 //! This game has been proposed in [Andreas Chen's "The Quicksort Game"](https://www.diva-portal.org/smash/get/diva2:935354/FULLTEXT01.pdf>).
 
 use std::fmt::Display;
 
-use crate::{display, short::impartial::impartial_game::ImpartialGame};
+use crate::{
+    display,
+    short::impartial::{
+        games::sorting::{QuicksortPivot, SortingGame},
+        impartial_game::ImpartialGame,
+    },
+};
 
 /// See [quickcheck](self) header
 #[derive(Debug, PartialEq, Eq)]
@@ -40,36 +48,17 @@ impl Quicksort {
     /// pivot on `pivot`
     #[must_use]
     pub fn pivot_on(&self, pivot: u32) -> Self {
-        let mut res = Self::new(Vec::with_capacity(self.sequence().len()));
-        for elem in self.sequence() {
-            if *elem < pivot {
-                res.sequence.push(*elem);
-            }
-        }
-
-        if self.sequence().contains(&pivot) {
-            res.sequence.push(pivot);
-        }
-
-        for elem in self.sequence() {
-            if *elem > pivot {
-                res.sequence.push(*elem);
-            }
-        }
-        res
+        Self::new(QuicksortPivot::pivot_on(self.sequence(), pivot))
     }
 }
 
 impl ImpartialGame for Quicksort {
     fn moves(&self) -> Vec<Self> {
-        let mut moves = Vec::with_capacity(self.sequence().len());
-        for pivot in self.sequence() {
-            let new = self.pivot_on(*pivot);
-            if &new != self {
-                moves.push(new);
-            }
-        }
-        moves
+        SortingGame::<QuicksortPivot>::new(self.sequence().clone())
+            .moves()
+            .into_iter()
+            .map(|m| Self::new(m.sequence().clone()))
+            .collect()
     }
 }
 
@@ -0,0 +1,209 @@
+//! Family of games played on a sequence of numbers, where players move by applying a step of
+//! a sorting algorithm. The set of allowed steps is a type parameter, so Nim values of different
+//! sorting variants can be compared on the same sequences.
+//!
+//! [Quicksort](crate::short::impartial::games::quicksort) is [`SortingGame`] with
+//! [`QuicksortPivot`] operation.
+
+use crate::{display, short::impartial::impartial_game::ImpartialGame};
+use std::{fmt::Display, marker::PhantomData};
+
+/// Step of a sorting algorithm, allowed as a move in a [`SortingGame`]
+pub trait SortingOperation {
+    /// Sequences obtained from `sequence` by applying the operation once. May include duplicates
+    /// and `sequence` itself, these are removed by [`SortingGame::moves`].
+    fn apply(sequence: &[u32]) -> Vec<Vec<u32>>;
+}
+
+/// Pivot around an element in the manner of the quicksort algorithm, see
+/// [quicksort](crate::short::impartial::games::quicksort).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QuicksortPivot;
+
+impl QuicksortPivot {
+    /// Move elements smaller than `pivot` before it and larger elements after it, keeping their
+    /// relative order. If `pivot` is not in the sequence it is not inserted.
+    pub fn pivot_on(sequence: &[u32], pivot: u32) -> Vec<u32> {
+        let mut res = Vec::with_capacity(sequence.len());
+        res.extend(sequence.iter().filter(|elem| **elem < pivot));
+        if sequence.contains(&pivot) {
+            res.push(pivot);
+        }
+        res.extend(sequence.iter().filter(|elem| **elem > pivot));
+        res
+    }
+}
+
+impl SortingOperation for QuicksortPivot {
+    fn apply(sequence: &[u32]) -> Vec<Vec<u32>> {
+        sequence
+            .iter()
+            .map(|pivot| Self::pivot_on(sequence, *pivot))
+            .collect()
+    }
+}
+
+/// Swap two neighbouring elements that are out of order, as in the bubble sort algorithm
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AdjacentSwap;
+
+impl SortingOperation for AdjacentSwap {
+    fn apply(sequence: &[u32]) -> Vec<Vec<u32>> {
+        (1..sequence.len())
+            .filter(|&idx| sequence[idx - 1] > sequence[idx])
+            .map(|idx| {
+                let mut res = sequence.to_vec();
+                res.swap(idx - 1, idx);
+                res
+            })
+            .collect()
+    }
+}
+
+/// Both operations are allowed
+impl<A, B> SortingOperation for (A, B)
+where
+    A: SortingOperation,
+    B: SortingOperation,
+{
+    fn apply(sequence: &[u32]) -> Vec<Vec<u32>> {
+        let mut res = A::apply(sequence);
+        res.extend(B::apply(sequence));
+        res
+    }
+}
+
+/// See [sorting](self) header
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SortingGame<O> {
+    sequence: Vec<u32>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    operation: PhantomData<O>,
+}
+
+impl<O> Display for SortingGame<O> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SortingGame")?;
+        display::brackets(f, |f| display::commas(f, self.sequence()))
+    }
+}
+
+impl<O> SortingGame<O> {
+    /// Create new position from a given sequence
+    #[inline]
+    pub const fn new(sequence: Vec<u32>) -> Self {
+        Self {
+            sequence,
+            operation: PhantomData,
+        }
+    }
+
+    /// Get the sequence of the position
+    #[inline]
+    pub const fn sequence(&self) -> &Vec<u32> {
+        &self.sequence
+    }
+}
+
+impl<O> ImpartialGame for SortingGame<O>
+where
+    O: SortingOperation,
+{
+    fn moves(&self) -> Vec<Self> {
+        let mut moves: Vec<Vec<u32>> = Vec::new();
+        for new in O::apply(self.sequence()) {
+            if !moves.contains(&new) && &new != self.sequence() {
+                moves.push(new);
+            }
+        }
+        moves.into_iter().map(Self::new).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{numeric::nimber::Nimber, short::impartial::games::quicksort::Quicksort};
+
+    /// All permutations of `1..=n`
+    fn permutations(n: u32) -> Vec<Vec<u32>> {
+        if n == 0 {
+            return vec![vec![]];
+        }
+        let mut res = Vec::new();
+        for shorter in permutations(n - 1) {
+            for idx in 0..=shorter.len() {
+                let mut permutation = shorter.clone();
+                permutation.insert(idx, n);
+                res.push(permutation);
+            }
+        }
+        res
+    }
+
+    #[test]
+    fn quicksort_variant_matches_quicksort() {
+        for n in 0..=6 {
+            for sequence in permutations(n) {
+                let game = SortingGame::<QuicksortPivot>::new(sequence.clone());
+                let quicksort = Quicksort::new(sequence.clone());
+
+                // Pivoting as in the original `Quicksort` implementation
+                let mut expected = sequence
+                    .iter()
+                    .map(|pivot| {
+                        let mut res = Vec::new();
+                        res.extend(sequence.iter().filter(|elem| *elem < pivot));
+                        res.push(*pivot);
+                        res.extend(sequence.iter().filter(|elem| *elem > pivot));
+                        res
+                    })
+                    .filter(|new| new != &sequence)
+                    .collect::<Vec<_>>();
+                expected.sort();
+                expected.dedup();
+                let mut moves = game
+                    .moves()
+                    .iter()
+                    .map(|m| m.sequence().clone())
+                    .collect::<Vec<_>>();
+                moves.sort();
+                assert_eq!(moves, expected, "{game}");
+
+                assert_eq!(game.nim_value(), quicksort.nim_value(), "{game}");
+            }
+        }
+
+        assert_eq!(
+            SortingGame::<QuicksortPivot>::new(vec![4, 1, 6, 5, 7, 3, 8, 2]).nim_value(),
+            Nimber::new(5)
+        );
+    }
+
+    #[test]
+    fn bubble_sort_parity() {
+        // Every swap removes exactly one inversion, so every play has the same length and only
+        // its parity matters
+        for n in 0..=5 {
+            for sequence in permutations(n) {
+                let inversions = (0..sequence.len())
+                    .flat_map(|i| (i + 1..sequence.len()).map(move |j| (i, j)))
+                    .filter(|&(i, j)| sequence[i] > sequence[j])
+                    .count() as u32;
+                let game = SortingGame::<AdjacentSwap>::new(sequence);
+                assert_eq!(game.nim_value(), Nimber::new(inversions % 2), "{game}");
+            }
+        }
+    }
+
+    #[test]
+    fn combined_operations() {
+        let sequence = vec![2, 1];
+        let game = SortingGame::<(QuicksortPivot, AdjacentSwap)>::new(sequence);
+        assert_eq!(game.moves(), vec![SortingGame::new(vec![1, 2])]);
+        assert_eq!(game.nim_value(), Nimber::new(1));
+    }
+}
@@ -0,0 +1,188 @@
+//! Wythoff's game is played on two heaps of tokens. In each turn a player removes any positive
+//! number of tokens from a single heap, or the same positive number of tokens from both heaps.
+
+use std::fmt::{self, Display};
+
+use crate::{
+    display,
+    drawing::svg::{self, ImmSvg},
+    short::impartial::impartial_game::{Enumerable, ImpartialGame},
+};
+
+/// Position of a [Wythoff's game](self)
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Wythoff {
+    first: u32,
+    second: u32,
+}
+
+impl Display for Wythoff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Wythoff")?;
+        display::parens(f, |f| display::commas(f, &[self.first, self.second]))
+    }
+}
+
+impl Wythoff {
+    /// Create new position with given heap sizes
+    #[inline]
+    pub const fn new(first: u32, second: u32) -> Self {
+        Self { first, second }
+    }
+
+    /// Get sizes of both heaps
+    #[inline]
+    pub const fn heaps(&self) -> (u32, u32) {
+        (self.first, self.second)
+    }
+
+    /// Check if the position is a P-position, i.e. its Nim value is zero.
+    ///
+    /// P-positions are `(a_n, b_n)` and `(b_n, a_n)` where `a_n` is the smallest number not used
+    /// by previous P-positions and `b_n = a_n + n`. These are the lower and upper Beatty sequences
+    /// of the golden ratio.
+    pub fn is_p_position(&self) -> bool {
+        let lower = self.first.min(self.second);
+        let upper = self.first.max(self.second);
+        u64::from(lower) == Self::lower_beatty(upper - lower)
+    }
+
+    /// Get `a_n = ⌊nφ⌋ = ⌊(n + √(5n²)) / 2⌋`. Taking integer square root does not change the
+    /// result, as `√(5n²)` is irrational for `n > 0`.
+    fn lower_beatty(n: u32) -> u64 {
+        let n = u128::from(n);
+        ((n + (5 * n * n).isqrt()) / 2) as u64
+    }
+
+    /// Render `max` by `max` grid of positions as SVG. Tile in column `x` and row `y` is the
+    /// position with heaps `(x, y)`, P-positions are filled. The lines `y = φx` and `x = φy`,
+    /// along which the Beatty sequences of P-positions lie, are highlighted.
+    pub fn to_svg<W>(max: u32, buf: &mut W) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        // Chosen arbitrarily
+        let tile_size = 48;
+        let grid_width = 4;
+
+        let offset = grid_width / 2;
+        let svg_size = max * tile_size + grid_width;
+        let viewport = svg::Viewport {
+            scale: tile_size as f32,
+            origin: (offset as i32, offset as i32),
+        };
+
+        ImmSvg::new(buf, svg_size, svg_size, |buf| {
+            for y in 0..max {
+                for x in 0..max {
+                    let fill = if Self::new(x, y).is_p_position() {
+                        "black"
+                    } else {
+                        "white"
+                    };
+                    let (rect_x, rect_y) = viewport.map(x as f32, y as f32);
+                    ImmSvg::rect(buf, rect_x, rect_y, tile_size, tile_size, fill)?;
+                }
+            }
+
+            let golden_ratio = (1.0 + 5.0_f32.sqrt()) / 2.0;
+            let (origin_x, origin_y) = viewport.map(0.0, 0.0);
+            let (steep_x, steep_y) = viewport.map(max as f32 / golden_ratio, max as f32);
+            let (shallow_x, shallow_y) = viewport.map(max as f32, max as f32 / golden_ratio);
+            ImmSvg::g(buf, "red", |buf| {
+                ImmSvg::line(buf, origin_x, origin_y, steep_x, steep_y, grid_width)?;
+                ImmSvg::line(buf, origin_x, origin_y, shallow_x, shallow_y, grid_width)
+            })?;
+
+            let grid = svg::Grid {
+                x1: 0,
+                y1: 0,
+                x2: svg_size as i32,
+                y2: svg_size as i32,
+                grid_width,
+                tile_size,
+            };
+            ImmSvg::grid(buf, &grid)
+        })
+    }
+}
+
+impl ImpartialGame for Wythoff {
+    fn moves(&self) -> Vec<Self> {
+        let mut moves = Vec::new();
+        for first in 0..self.first {
+            moves.push(Self::new(first, self.second));
+        }
+        for second in 0..self.second {
+            moves.push(Self::new(self.first, second));
+        }
+        for taken in 1..=self.first.min(self.second) {
+            moves.push(Self::new(self.first - taken, self.second - taken));
+        }
+        moves
+    }
+}
+
+impl Enumerable for Wythoff {
+    /// All positions with at most `bound` tokens in total
+    fn positions_up_to(bound: u32) -> Vec<Self> {
+        (0..=bound)
+            .flat_map(|first| (0..=bound - first).map(move |second| Self::new(first, second)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{numeric::nimber::Nimber, short::impartial::impartial_game::impartial_value_table};
+
+    #[test]
+    fn p_positions_match_nim_values() {
+        let table = impartial_value_table::<Wythoff>(20);
+        for (position, value) in &table {
+            assert_eq!(
+                position.is_p_position(),
+                *value == Nimber::new(0),
+                "{position}"
+            );
+        }
+
+        for (first, second) in [(0, 0), (1, 2), (3, 5), (4, 7), (6, 10), (8, 13)] {
+            assert!(Wythoff::new(first, second).is_p_position());
+            assert!(Wythoff::new(second, first).is_p_position());
+        }
+    }
+
+    #[test]
+    fn p_positions_match_definition() {
+        let mut used = vec![false; 2048];
+        let mut a_n = 0;
+        for n in 0..512 {
+            while used[a_n as usize] {
+                a_n += 1;
+            }
+            used[a_n as usize] = true;
+            used[(a_n + n) as usize] = true;
+            assert!(Wythoff::new(a_n, a_n + n).is_p_position(), "{n}");
+            assert!(!Wythoff::new(a_n + 1, a_n + n + 1).is_p_position(), "{n}");
+        }
+    }
+
+    #[test]
+    fn p_positions_of_large_heaps() {
+        assert!(!Wythoff::new(0, u32::MAX).is_p_position());
+        assert!(!Wythoff::new(u32::MAX, u32::MAX).is_p_position());
+        assert!(Wythoff::new(1_618_033_988, 2_618_033_988).is_p_position());
+        assert!(!Wythoff::new(1_618_033_989, 2_618_033_989).is_p_position());
+    }
+
+    #[test]
+    fn origin_is_marked_in_svg() {
+        let mut svg = String::new();
+        Wythoff::to_svg(4, &mut svg).unwrap();
+        assert!(svg.contains(r#"<rect x="2" y="2" width="48" height="48" style="fill:black;"/>"#));
+        assert!(svg.contains(r#"<rect x="50" y="2" width="48" height="48" style="fill:white;"/>"#));
+    }
+}
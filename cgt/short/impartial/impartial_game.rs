@@ -1,6 +1,8 @@
 //! Impartial game - both players have the same moves
 
 use crate::numeric::nimber::Nimber;
+use ahash::{HashMap, HashMapExt};
+use std::hash::Hash;
 
 /// Impartial game
 pub trait ImpartialGame: Sized {
@@ -17,3 +19,40 @@ pub trait ImpartialGame: Sized {
         Nimber::mex(game_moves)
     }
 }
+
+/// Game with finitely many positions up to a size bound
+pub trait Enumerable: Sized {
+    /// List all positions with size at most `bound`. Meaning of size depends on the game.
+    fn positions_up_to(bound: u32) -> Vec<Self>;
+}
+
+/// Compute Nim values of all positions up to `bound`, see [`Enumerable::positions_up_to`].
+/// Positions reachable from them are included as well.
+pub fn impartial_value_table<G>(bound: u32) -> HashMap<G, Nimber>
+where
+    G: ImpartialGame + Enumerable + Hash + Eq + Clone,
+{
+    fn nim_value<G>(position: &G, table: &mut HashMap<G, Nimber>) -> Nimber
+    where
+        G: ImpartialGame + Hash + Eq + Clone,
+    {
+        if let Some(value) = table.get(position) {
+            return *value;
+        }
+
+        let moves = position
+            .moves()
+            .iter()
+            .map(|m| nim_value(m, table))
+            .collect::<Vec<_>>();
+        let value = Nimber::mex(moves);
+        table.insert(position.clone(), value);
+        value
+    }
+
+    let mut table = HashMap::new();
+    for position in G::positions_up_to(bound) {
+        nim_value(&position, &mut table);
+    }
+    table
+}
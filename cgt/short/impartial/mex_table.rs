@@ -0,0 +1,136 @@
+//! Cached Grundy sequences of heap games defined by a successor function
+
+use crate::numeric::nimber::Nimber;
+
+/// Periodicity of a Grundy sequence, see [`MexTable::periodicity`]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Periodicity {
+    /// Number of values before the periodic part starts
+    pub preperiod: usize,
+
+    /// Length of the repeating part
+    pub period: usize,
+}
+
+/// Grundy sequence of a heap game where heap of size `n` can be reduced to any of
+/// `successors(n)`. Generalizes games like Nim or subtraction and octal games with a single heap.
+#[derive(Debug, Clone)]
+pub struct MexTable<F> {
+    successors: F,
+    values: Vec<Nimber>,
+}
+
+impl<F> MexTable<F>
+where
+    F: Fn(u32) -> Vec<u32>,
+{
+    /// Create new table without any computed values
+    pub const fn new(successors: F) -> Self {
+        Self {
+            successors,
+            values: Vec::new(),
+        }
+    }
+
+    /// Compute Grundy values of all heaps up to and including `n`
+    ///
+    /// # Panics
+    /// - Successor of a heap is not a smaller heap
+    pub fn compute_up_to(&mut self, n: u32) {
+        for heap in (self.values.len() as u32)..=n {
+            let successors = (self.successors)(heap)
+                .into_iter()
+                .map(|successor| {
+                    assert!(successor < heap, "successor must be smaller than the heap");
+                    self.values[successor as usize]
+                })
+                .collect::<Vec<_>>();
+            self.values.push(Nimber::mex(successors));
+        }
+    }
+
+    /// Get Grundy value of a heap of size `n`, computing it if necessary
+    ///
+    /// # Panics
+    /// - Successor of a heap is not a smaller heap
+    pub fn grundy_value(&mut self, n: u32) -> Nimber {
+        self.compute_up_to(n);
+        self.values[n as usize]
+    }
+
+    /// Get all Grundy values computed so far, starting from the empty heap
+    pub fn values(&self) -> &[Nimber] {
+        &self.values
+    }
+
+    /// Find periodicity of computed values with the shortest preperiod and period combined.
+    /// Periodic part must repeat at least twice. Returns [None] if no such periodicity exists.
+    ///
+    /// Note that values that were not computed yet are not checked, so the sequence may break the
+    /// periodicity later.
+    pub fn periodicity(&self) -> Option<Periodicity> {
+        let len = self.values.len();
+        (1..=len / 2)
+            .filter_map(|period| {
+                let preperiod = (0..len - period)
+                    .rev()
+                    .find(|&i| self.values[i] != self.values[i + period])
+                    .map_or(0, |i| i + 1);
+                (len - preperiod >= 2 * period).then_some(Periodicity { preperiod, period })
+            })
+            .min_by_key(|periodicity| {
+                (
+                    periodicity.preperiod + periodicity.period,
+                    periodicity.period,
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn subtraction(subtraction_set: &'static [u32]) -> impl Fn(u32) -> Vec<u32> {
+        move |heap| {
+            subtraction_set
+                .iter()
+                .filter(|&&m| m <= heap)
+                .map(|m| heap - m)
+                .collect()
+        }
+    }
+
+    #[test]
+    fn subtraction_periodicity() {
+        let mut table = MexTable::new(subtraction(&[1, 2]));
+        table.compute_up_to(30);
+        assert_eq!(&table.values()[..6], &[0, 1, 2, 0, 1, 2].map(Nimber::new));
+        assert_eq!(
+            table.periodicity(),
+            Some(Periodicity {
+                preperiod: 0,
+                period: 3
+            })
+        );
+
+        let mut table = MexTable::new(subtraction(&[2, 3, 5]));
+        assert_eq!(table.grundy_value(40), Nimber::new(2));
+        assert_eq!(
+            table.periodicity(),
+            Some(Periodicity {
+                preperiod: 0,
+                period: 7
+            })
+        );
+    }
+
+    #[test]
+    fn nim_is_not_periodic() {
+        let mut table = MexTable::new(|heap| (0..heap).collect());
+        table.compute_up_to(20);
+        assert_eq!(table.grundy_value(17), Nimber::new(17));
+        assert_eq!(table.periodicity(), None);
+    }
+}
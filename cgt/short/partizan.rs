@@ -2,7 +2,9 @@
 
 pub mod canonical_form;
 pub mod games;
+pub mod outcome;
 pub mod partizan_game;
+pub mod symmetry;
 pub mod thermograph;
 pub mod trajectory;
 pub mod transposition_table;
@@ -11,18 +11,28 @@ use crate::{
     short::partizan::trajectory::Trajectory,
 };
 use auto_ops::impl_op_ex;
+use itertools::Either;
 use nom::{
     branch::alt,
     character::complete::{char, one_of, u32},
     error::ErrorKind,
-    multi::separated_list0,
+    multi::{fold_many0, separated_list0},
+    sequence::pair,
 };
 use std::{
+    cell::Cell,
     cmp::Ordering,
     fmt::{self, Display, Write},
     hash::Hash,
+    iter::Sum,
+    rc::Rc,
 };
 
+#[cfg(feature = "statistics")]
+use std::sync::atomic::{self, AtomicUsize};
+
+pub mod constants;
+
 /// A number-up-star game position that is a sum of a number, up and, nimber.
 #[derive(Debug, Hash, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -356,6 +366,33 @@ impl Ord for Moves {
 }
 
 impl Moves {
+    /// Start building moves one option at a time
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cgt::short::partizan::canonical_form::{CanonicalForm, Moves};
+    ///
+    /// let moves = Moves::builder()
+    ///     .left(CanonicalForm::new_integer(1))
+    ///     .right(CanonicalForm::new_integer(-1))
+    ///     .build();
+    /// assert_eq!(&CanonicalForm::new_from_moves(moves).to_string(), "{1|-1}");
+    /// ```
+    pub const fn builder() -> MovesBuilder {
+        MovesBuilder {
+            moves: Self::empty(),
+        }
+    }
+
+    /// Moves where both players have the same options, e.g. `{0, *|0, *}`
+    pub fn symmetric(options: Vec<CanonicalForm>) -> Self {
+        Self {
+            left: options.clone(),
+            right: options,
+        }
+    }
+
     #[inline]
     const fn empty() -> Self {
         Self {
@@ -541,6 +578,8 @@ impl Moves {
         eliminate_smaller_moves: bool,
     ) -> Vec<CanonicalForm> {
         let mut moves: Vec<Option<CanonicalForm>> = moves.iter().cloned().map(Some).collect();
+        #[cfg(feature = "statistics")]
+        let total_moves = moves.len();
 
         'outer: for i in 0..moves.len() {
             'inner: for j in 0..i {
@@ -568,7 +607,10 @@ impl Moves {
             }
         }
 
-        moves.iter().flatten().cloned().collect()
+        let moves: Vec<CanonicalForm> = moves.iter().flatten().cloned().collect();
+        #[cfg(feature = "statistics")]
+        DOMINATED_REMOVED.fetch_add(total_moves - moves.len(), atomic::Ordering::Relaxed);
+        moves
     }
 
     /// Return false if `H <= GL` for some left option `GL` of `G` or `HR <= G` for some right
@@ -650,6 +692,8 @@ impl Moves {
                         }
                     }
                     left_moves = new_left_moves;
+                    #[cfg(feature = "statistics")]
+                    REVERSIBLE_BYPASSED.fetch_add(1, atomic::Ordering::Relaxed);
                     i -= 1;
                     break;
                 }
@@ -697,6 +741,8 @@ impl Moves {
                         }
                     }
                     right_moves = new_right_moves;
+                    #[cfg(feature = "statistics")]
+                    REVERSIBLE_BYPASSED.fetch_add(1, atomic::Ordering::Relaxed);
                     i -= 1;
                     break;
                 }
@@ -817,6 +863,180 @@ enum CanonicalFormInner {
     Moves(Moves),
 }
 
+/// Classification of games by their temperature
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ThermalClass {
+    /// Temperature is positive, players want to move
+    Hot,
+
+    /// Temperature is zero, e.g. infinitesimals
+    Tepid,
+
+    /// Temperature is negative, i.e. the game is a number
+    Cold,
+}
+
+impl Display for ThermalClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Hot => write!(f, "Hot"),
+            Self::Tepid => write!(f, "Tepid"),
+            Self::Cold => write!(f, "Cold"),
+        }
+    }
+}
+
+/// Fate of a single option during reduction to canonical form
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Reduction {
+    /// Option is kept in the canonical form
+    Kept,
+
+    /// Option is dominated by another option of the same player
+    Dominated(CanonicalForm),
+
+    /// Option is reversible through its option for the opponent
+    Reversible(CanonicalForm),
+}
+
+/// Explanation of how options were reduced, see [`CanonicalForm::explain_reduction`]
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReductionReport {
+    /// Original Left options in the input order
+    pub left: Vec<(CanonicalForm, Reduction)>,
+
+    /// Original Right options in the input order
+    pub right: Vec<(CanonicalForm, Reduction)>,
+
+    /// Canonical form of the game
+    pub canonical_form: CanonicalForm,
+}
+
+/// Number of options removed while constructing canonical forms, see
+/// [`CanonicalForm::simplification_stats`]
+#[cfg(feature = "statistics")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SimplificationStats {
+    /// Number of dominated options removed
+    pub dominated_removed: usize,
+
+    /// Number of reversible options bypassed
+    pub reversible_bypassed: usize,
+}
+
+#[cfg(feature = "statistics")]
+static DOMINATED_REMOVED: AtomicUsize = AtomicUsize::new(0);
+
+#[cfg(feature = "statistics")]
+static REVERSIBLE_BYPASSED: AtomicUsize = AtomicUsize::new(0);
+
+/// Builder for [`Moves`], see [`Moves::builder`]
+#[derive(Debug, Clone)]
+pub struct MovesBuilder {
+    moves: Moves,
+}
+
+impl MovesBuilder {
+    /// Add an option for the Left player
+    #[must_use]
+    pub fn left(mut self, option: CanonicalForm) -> Self {
+        self.moves.left.push(option);
+        self
+    }
+
+    /// Add an option for the Right player
+    #[must_use]
+    pub fn right(mut self, option: CanonicalForm) -> Self {
+        self.moves.right.push(option);
+        self
+    }
+
+    /// Finish building moves
+    pub fn build(self) -> Moves {
+        self.moves
+    }
+}
+
+/// Iterator over all antichains of games, i.e. sets of pairwise incomparable games, in
+/// lexicographic order of their indices. The empty antichain comes first.
+#[derive(Clone)]
+struct Antichains {
+    games: Rc<[CanonicalForm]>,
+    // `incomparable[i * games.len() + j]` is `Some(true)` if games `i` and `j` are incomparable,
+    // computed on first use as there are too many pairs to compare upfront on later days
+    incomparable: Rc<[Cell<Option<bool>>]>,
+    current: Vec<usize>,
+    started: bool,
+}
+
+impl Antichains {
+    fn new(games: Vec<CanonicalForm>) -> Self {
+        Self {
+            incomparable: vec![Cell::new(None); games.len() * games.len()].into(),
+            games: games.into(),
+            current: Vec::new(),
+            started: false,
+        }
+    }
+
+    fn are_incomparable(&self, lhs: usize, rhs: usize) -> bool {
+        let cached = &self.incomparable[lhs * self.games.len() + rhs];
+        cached.get().unwrap_or_else(|| {
+            let (lhs, rhs) = (&self.games[lhs], &self.games[rhs]);
+            let incomparable = !CanonicalForm::leq(lhs, rhs) && !CanonicalForm::leq(rhs, lhs);
+            cached.set(Some(incomparable));
+            incomparable
+        })
+    }
+
+    /// Smallest index at least `from` that can extend the current antichain
+    fn next_candidate(&self, from: usize) -> Option<usize> {
+        (from..self.games.len()).find(|&candidate| {
+            self.current
+                .iter()
+                .all(|&idx| self.are_incomparable(idx, candidate))
+        })
+    }
+}
+
+impl Iterator for Antichains {
+    type Item = Vec<CanonicalForm>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.started {
+            let from = self.current.last().map_or(0, |last| last + 1);
+            if let Some(candidate) = self.next_candidate(from) {
+                self.current.push(candidate);
+            } else {
+                // Replace the last element with the next possible one, backtracking further if
+                // there is none
+                loop {
+                    let last = self.current.pop()?;
+                    if let Some(candidate) = self.next_candidate(last + 1) {
+                        self.current.push(candidate);
+                        break;
+                    }
+                }
+            }
+        }
+        self.started = true;
+
+        Some(
+            self.current
+                .iter()
+                .map(|&idx| self.games[idx].clone())
+                .collect(),
+        )
+    }
+}
+
+const BYTES_NUS_TAG: u8 = 0;
+const BYTES_MOVES_TAG: u8 = 1;
+
 /// Canonical game form
 #[repr(transparent)]
 #[derive(Debug, Hash, Clone, PartialEq, Eq)]
@@ -855,33 +1075,120 @@ impl CanonicalForm {
         Self::from_inner(CanonicalFormInner::Nus(nus))
     }
 
-    /// Construct negative.0 of a game. Alias for negation [`-`] operator
+    /// Construct negative of a game. Alias for negation [`-`] operator
+    ///
+    /// Negative of a canonical form is canonical, and negative of a game that is not a NUS is not
+    /// a NUS, so options are only swapped and negated, without any simplification. This takes
+    /// time linear in the size of the game.
     #[must_use]
     pub fn construct_negative(&self) -> Self {
         match &self.inner {
             CanonicalFormInner::Nus(nus) => Self::new_nus(-nus),
             CanonicalFormInner::Moves(moves) => {
-                let new_left_moves = moves
-                    .left
-                    .iter()
-                    .map(Self::construct_negative)
-                    .collect::<Vec<_>>();
-                let new_right_moves = moves
-                    .right
-                    .iter()
-                    .map(Self::construct_negative)
-                    .collect::<Vec<_>>();
-                let new_moves = Moves {
-                    left: new_left_moves,
-                    right: new_right_moves,
+                let negate_sorted = |options: &[Self]| {
+                    let mut negated = options
+                        .iter()
+                        .map(Self::construct_negative)
+                        .collect::<Vec<_>>();
+                    negated.sort_by(|lhs, rhs| lhs.inner.cmp(&rhs.inner));
+                    negated
                 };
-                Self::construct_from_canonical_moves(new_moves)
+                Self::from_inner(CanonicalFormInner::Moves(Moves {
+                    left: negate_sorted(&moves.right),
+                    right: negate_sorted(&moves.left),
+                }))
             }
         }
     }
 
     /// Construct a sum of two games. Alias for [`+`] operator
     pub fn construct_sum(g: &Self, h: &Self) -> Self {
+        let result = Self::construct_sum_unverified(g, h);
+        #[cfg(feature = "debug_verify")]
+        result.verify_identities();
+        result
+    }
+
+    /// Check that `G + 0 == G` and `G + (-G) == 0`, enabled by `debug_verify` feature. Games
+    /// with more than [`VERIFY_MAX_NODES`](Self::VERIFY_MAX_NODES) nodes are skipped, as the
+    /// inverse of `G` takes time quadratic in the size of `G` to verify.
+    #[cfg(any(test, feature = "debug_verify"))]
+    fn verify_identities(&self) {
+        if self.num_nodes_up_to(Self::VERIFY_MAX_NODES + 1) > Self::VERIFY_MAX_NODES {
+            return;
+        }
+
+        let zero = Self::new_integer(0);
+        assert_eq!(
+            &Self::construct_sum_unverified(self, &zero),
+            self,
+            "G + 0 != G for G = {self}"
+        );
+        assert_eq!(
+            Self::construct_sum_memoized(
+                self,
+                &self.construct_negative(),
+                &mut std::collections::HashMap::new()
+            ),
+            zero,
+            "G + (-G) != 0 for G = {self}"
+        );
+    }
+
+    /// Largest game checked by [`CanonicalForm::verify_identities`]
+    #[cfg(any(test, feature = "debug_verify"))]
+    const VERIFY_MAX_NODES: usize = 32;
+
+    /// Number of nodes in the game tree, counting stops once `limit` is reached
+    #[cfg(any(test, feature = "debug_verify"))]
+    fn num_nodes_up_to(&self, limit: usize) -> usize {
+        match &self.inner {
+            CanonicalFormInner::Nus(_) => 1,
+            CanonicalFormInner::Moves(moves) => {
+                let mut nodes = 1;
+                for option in moves.left.iter().chain(moves.right.iter()) {
+                    if nodes >= limit {
+                        break;
+                    }
+                    nodes += option.num_nodes_up_to(limit - nodes);
+                }
+                nodes
+            }
+        }
+    }
+
+    /// Sum of two games where sums of subgames are cached in `memo`. Every subgame of `G` is added
+    /// to every subgame of `H`, so without caching the same pairs are summed exponentially many
+    /// times in deep games.
+    #[cfg(any(test, feature = "debug_verify"))]
+    fn construct_sum_memoized(
+        g: &Self,
+        h: &Self,
+        memo: &mut std::collections::HashMap<(Self, Self), Self>,
+    ) -> Self {
+        if let Some(sum) = memo.get(&(g.clone(), h.clone())) {
+            return sum.clone();
+        }
+        let sum = Self::construct_sum_with(g, h, |g, h| Self::construct_sum_memoized(g, h, memo));
+        memo.insert((g.clone(), h.clone()), sum.clone());
+        sum
+    }
+
+    /// Worker of [`CanonicalForm::construct_sum`]. Recursive sums are not verified to avoid
+    /// verifying the same subgames repeatedly.
+    fn construct_sum_unverified(g: &Self, h: &Self) -> Self {
+        Self::construct_sum_with(g, h, Self::construct_sum_unverified)
+    }
+
+    /// Sum of two games, where sums of options are computed with `sum`
+    fn construct_sum_with(g: &Self, h: &Self, mut sum: impl FnMut(&Self, &Self) -> Self) -> Self {
+        if g.is_zero() {
+            return h.clone();
+        }
+        if h.is_zero() {
+            return g.clone();
+        }
+
         if let (CanonicalFormInner::Nus(g_nus), CanonicalFormInner::Nus(h_nus)) =
             (&g.inner, &h.inner)
         {
@@ -897,19 +1204,19 @@ impl CanonicalForm {
         if !g.is_number() {
             let g_moves = g.to_moves();
             for g_l in &g_moves.left {
-                moves.left.push(Self::construct_sum(g_l, h));
+                moves.left.push(sum(g_l, h));
             }
             for g_r in &g_moves.right {
-                moves.right.push(Self::construct_sum(g_r, h));
+                moves.right.push(sum(g_r, h));
             }
         }
         if !h.is_number() {
             let h_moves = h.to_moves();
             for h_l in &h_moves.left {
-                moves.left.push(Self::construct_sum(g, h_l));
+                moves.left.push(sum(g, h_l));
             }
             for h_r in &h_moves.right {
-                moves.right.push(Self::construct_sum(g, h_r));
+                moves.right.push(sum(g, h_r));
             }
         }
 
@@ -930,6 +1237,10 @@ impl CanonicalForm {
     }
 
     /// Safe function to construct a game from possible moves
+    ///
+    /// Either side may have no moves. A game where Right has no moves is a non-negative integer,
+    /// e.g. `{0|} = 1`, `{1/2|} = 1`, and `{*|} = 0`. Symmetrically, a game where Left has no moves
+    /// is a non-positive integer.
     pub fn new_from_moves(mut moves: Moves) -> Self {
         moves.eliminate_duplicates();
         moves = moves.canonicalize();
@@ -937,11 +1248,219 @@ impl CanonicalForm {
         Self::construct_from_canonical_moves(moves)
     }
 
+    /// Construct a game from options of both players, see [`CanonicalForm::new_from_moves`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cgt::short::partizan::canonical_form::CanonicalForm;
+    ///
+    /// let one = CanonicalForm::new_integer(1);
+    /// let game = CanonicalForm::from_options(&[one.clone()], &[-one]);
+    /// assert_eq!(game.to_string(), "{1|-1}");
+    /// ```
+    pub fn from_options(left: &[Self], right: &[Self]) -> Self {
+        Self::new_from_moves(Moves {
+            left: left.to_vec(),
+            right: right.to_vec(),
+        })
+    }
+
+    /// Get the number of options removed by all canonical form constructions so far, enabled by
+    /// `statistics` feature. Counters are shared between threads and never reset.
+    #[cfg(feature = "statistics")]
+    pub fn simplification_stats() -> SimplificationStats {
+        SimplificationStats {
+            dominated_removed: DOMINATED_REMOVED.load(atomic::Ordering::Relaxed),
+            reversible_bypassed: REVERSIBLE_BYPASSED.load(atomic::Ordering::Relaxed),
+        }
+    }
+
+    /// Tag every option with the reason it was removed when constructing canonical form, or
+    /// [`Reduction::Kept`] if it is not removed.
+    ///
+    /// Options are checked for domination first, and only undominated options are checked for
+    /// reversibility. Of equal options the first one is kept and the rest are dominated by it.
+    /// Replacement options from bypassing reversible moves are not listed.
+    pub fn explain_reduction(moves: Moves) -> ReductionReport {
+        let canonical_form = Self::new_from_moves(moves.clone());
+
+        let left = moves
+            .left
+            .iter()
+            .enumerate()
+            .map(|(i, g_l)| {
+                let dominating = moves
+                    .left
+                    .iter()
+                    .enumerate()
+                    .find(|(j, other)| *j != i && g_l <= *other && (*j < i || g_l != *other));
+                let reduction = if let Some((_, other)) = dominating {
+                    Reduction::Dominated(other.clone())
+                } else if let Some(g_lr) = g_l
+                    .to_moves()
+                    .right
+                    .into_iter()
+                    .find(|g_lr| g_lr <= &canonical_form)
+                {
+                    Reduction::Reversible(g_lr)
+                } else {
+                    Reduction::Kept
+                };
+                (g_l.clone(), reduction)
+            })
+            .collect();
+
+        let right = moves
+            .right
+            .iter()
+            .enumerate()
+            .map(|(i, g_r)| {
+                let dominating = moves
+                    .right
+                    .iter()
+                    .enumerate()
+                    .find(|(j, other)| *j != i && *other <= g_r && (*j < i || g_r != *other));
+                let reduction = if let Some((_, other)) = dominating {
+                    Reduction::Dominated(other.clone())
+                } else if let Some(g_rl) = g_r
+                    .to_moves()
+                    .left
+                    .into_iter()
+                    .find(|g_rl| &canonical_form <= g_rl)
+                {
+                    Reduction::Reversible(g_rl)
+                } else {
+                    Reduction::Kept
+                };
+                (g_r.clone(), reduction)
+            })
+            .collect();
+
+        ReductionReport {
+            left,
+            right,
+            canonical_form,
+        }
+    }
+
+    /// Construct tiny `⧾_G = {0 || 0 | -G}`, positive infinitesimal for positive `G`
+    pub fn tiny(g: &Self) -> Self {
+        let zero = Self::new_integer(0);
+        let right = Self::new_from_moves(Moves {
+            left: vec![zero.clone()],
+            right: vec![-g],
+        });
+        Self::new_from_moves(Moves {
+            left: vec![zero],
+            right: vec![right],
+        })
+    }
+
+    /// Construct switch `±n = {n | -n}`. `±0` is `*`, and for negative `n` the game is `0`.
+    pub fn switch(n: &DyadicRationalNumber) -> Self {
+        Self::new_from_moves(Moves {
+            left: vec![Self::new_dyadic(*n)],
+            right: vec![Self::new_dyadic(-*n)],
+        })
+    }
+
+    /// Get `n` if the game is a switch `±n = {n | -n}` with non-negative `n`, see
+    /// [`CanonicalForm::switch`]. `*` is `±0`.
+    pub fn as_switch(&self) -> Option<DyadicRationalNumber> {
+        if self.star_count() == Some(1) {
+            return Some(DyadicRationalNumber::from(0));
+        }
+
+        let CanonicalFormInner::Moves(ref moves) = self.inner else {
+            return None;
+        };
+        let ([left], [right]) = (&moves.left[..], &moves.right[..]) else {
+            return None;
+        };
+        let n = left.to_number()?;
+        (right.to_number()? == -n).then_some(n)
+    }
+
+    /// Construct miny `⧿_G = {G | 0 || 0}`, the negative of [`CanonicalForm::tiny`]
+    pub fn miny(g: &Self) -> Self {
+        let zero = Self::new_integer(0);
+        let left = Self::new_from_moves(Moves {
+            left: vec![g.clone()],
+            right: vec![zero.clone()],
+        });
+        Self::new_from_moves(Moves {
+            left: vec![left],
+            right: vec![zero],
+        })
+    }
+
+    /// All game values born by `day`, i.e. with birthday at most `day`, each appearing once.
+    ///
+    /// Options of a game in canonical form are antichains of values born on the previous day, so
+    /// only pairs of antichains are tried. Values born by `day - 1` are computed upfront, and
+    /// values born by `day` are generated lazily. Number of values grows doubly exponentially:
+    /// there are 1, 4, 22, and 1474 values born by days 0, 1, 2, and 3 respectively, and listing
+    /// values born by day 4 is not feasible.
+    ///
+    /// # Examples
+    /// ```
+    /// use cgt::short::partizan::canonical_form::CanonicalForm;
+    ///
+    /// let mut day_one = CanonicalForm::born_by(1).map(|g| g.to_string()).collect::<Vec<_>>();
+    /// day_one.sort();
+    /// assert_eq!(day_one, vec!["*", "-1", "0", "1"]);
+    /// ```
+    pub fn born_by(day: u32) -> impl Iterator<Item = Self> {
+        let mut born = vec![Self::new_integer(0)];
+        if day == 0 {
+            return Either::Left(born.into_iter());
+        }
+
+        for _ in 1..day {
+            born = Self::born_next_day(born).collect();
+        }
+        Either::Right(Self::born_next_day(born))
+    }
+
+    /// Values born by the day after the day `born` were born by, see [`CanonicalForm::born_by`]
+    fn born_next_day(born: Vec<Self>) -> impl Iterator<Item = Self> {
+        let antichains = Antichains::new(born);
+        let mut seen = ahash::HashSet::default();
+        antichains
+            .clone()
+            .flat_map(move |left| {
+                antichains.clone().map(move |right| Moves {
+                    left: left.clone(),
+                    right,
+                })
+            })
+            .map(Self::new_from_moves)
+            .filter(move |game| seen.insert(game.clone()))
+    }
+
     #[inline]
     const fn from_inner(inner: CanonicalFormInner) -> Self {
         Self { inner }
     }
 
+    /// Write the game in the same notation as [`Display`], without allocating intermediate
+    /// [`String`]
+    ///
+    /// # Errors
+    /// - Writer fails
+    pub fn write_to<W>(&self, w: &mut W) -> fmt::Result
+    where
+        W: Write,
+    {
+        write!(w, "{self}")
+    }
+
+    /// Display the game together with its temperature, e.g. `{1*|-1*} (t=1)`
+    pub fn to_string_with_temperature(&self) -> String {
+        format!("{} (t={})", self, self.temperature())
+    }
+
     /// Get left and right moves from a canonical form
     pub fn to_moves(&self) -> Moves {
         match &self.inner {
@@ -956,6 +1475,12 @@ impl CanonicalForm {
         matches!(self.inner, CanonicalFormInner::Nus(_))
     }
 
+    /// Check if the game is zero
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        matches!(self.inner, CanonicalFormInner::Nus(nus) if nus == Nus::new_integer(0))
+    }
+
     /// Check if a game is only a number
     #[inline]
     pub fn is_number(&self) -> bool {
@@ -991,6 +1516,40 @@ impl CanonicalForm {
             .and_then(|nus| nus.is_number().then_some(nus.number()))
     }
 
+    /// Get `(n, has_star)` if the game is equal to `n·↑`, or to `n·↑ + *` when `has_star` is
+    /// `true`. Note that `n` can be zero or negative, e.g. `*` is `(0, true)` and `v` is
+    /// `(-1, false)`.
+    pub fn up_multiple(&self) -> Option<(i64, bool)> {
+        self.to_nus().and_then(|nus| {
+            let has_star = match nus.nimber().value() {
+                0 => false,
+                1 => true,
+                _ => return None,
+            };
+            (nus.number() == DyadicRationalNumber::from(0))
+                .then_some((i64::from(nus.up_multiple()), has_star))
+        })
+    }
+
+    /// Get `n` if the game is equal to the nimber `*n`
+    pub fn star_count(&self) -> Option<u32> {
+        self.to_nus()
+            .and_then(|nus| nus.is_nimber().then_some(nus.nimber().value()))
+    }
+
+    /// Get nimber `*n` such that `self - other == *n`, if it exists. Useful for checking if
+    /// games are equal up to a star.
+    pub fn nimber_difference(&self, other: &Self) -> Option<Nimber> {
+        (self - other)
+            .to_nus()
+            .and_then(|nus| nus.is_nimber().then_some(nus.nimber()))
+    }
+
+    /// Compare two games if both are numbers, in which case the order is total
+    pub fn cmp_numbers(&self, other: &Self) -> Option<Ordering> {
+        Some(self.to_number()?.cmp(&other.to_number()?))
+    }
+
     /// Less than or equals comparison on two games
     pub fn leq(lhs_game: &Self, rhs_game: &Self) -> bool {
         // NOTE: There is a possible optimization.
@@ -1054,6 +1613,42 @@ impl CanonicalForm {
         }
     }
 
+    /// Calculate temperature of a sum of games, without constructing the sum when possible
+    ///
+    /// Shortcuts are taken when
+    /// - all components are numbers, then the sum is a number and its temperature is known
+    /// - exactly one component is not a number, then the temperature is the temperature of that
+    ///   component (i.e. the maximum of component temperatures), as adding a number does not
+    ///   change the temperature of a game that is not a number
+    /// - all components are numbers plus infinitesimals (NUS), then the sum is a NUS that can
+    ///   be computed directly
+    ///
+    /// Otherwise the full sum is constructed.
+    pub fn sum_temperature(components: &[Self]) -> DyadicRationalNumber {
+        let mut non_numbers = components.iter().filter(|component| !component.is_number());
+        match (non_numbers.next(), non_numbers.next()) {
+            (None, _) => {
+                let sum = components
+                    .iter()
+                    .filter_map(Self::to_number)
+                    .fold(DyadicRationalNumber::from(0), |acc, number| acc + number);
+                Self::new_dyadic(sum).temperature()
+            }
+            (Some(non_number), None) => non_number.temperature(),
+            (Some(_), Some(_)) => {
+                if let Some(nuses) = components
+                    .iter()
+                    .map(Self::to_nus)
+                    .collect::<Option<Vec<_>>>()
+                {
+                    let sum = nuses.iter().fold(Nus::new_integer(0), |acc, nus| acc + nus);
+                    return Self::new_nus(sum).temperature();
+                }
+                components.iter().sum::<Self>().temperature()
+            }
+        }
+    }
+
     /// Construct a thermograph of a game, using thermographic intersection of
     /// left and right scaffolds
     pub fn thermograph(&self) -> Thermograph {
@@ -1114,10 +1709,27 @@ impl CanonicalForm {
             .right
             .iter()
             .map(Self::left_stop)
-            .max()
+            .min()
             .expect("Not a number so must have moves")
     }
 
+    /// Check if `self - other` is infinitesimal, i.e. lies strictly between all negative and all
+    /// positive numbers. Equivalently, both stops of the difference are zero.
+    pub fn is_infinitesimally_close_to(&self, other: &Self) -> bool {
+        let difference = self - other;
+        let zero = DyadicRationalNumber::from(0);
+        difference.left_stop() == zero && difference.right_stop() == zero
+    }
+
+    /// Classify game as hot, tepid, or cold based on its [temperature](Self::temperature)
+    pub fn thermal_class(&self) -> ThermalClass {
+        match self.temperature().cmp(&DyadicRationalNumber::from(0)) {
+            Ordering::Greater => ThermalClass::Hot,
+            Ordering::Equal => ThermalClass::Tepid,
+            Ordering::Less => ThermalClass::Cold,
+        }
+    }
+
     /// Confusion interval is the region between Left and Right stops
     pub fn confusion_interval(&self) -> (DyadicRationalNumber, DyadicRationalNumber) {
         (self.left_stop(), self.right_stop())
@@ -1137,6 +1749,20 @@ impl CanonicalForm {
         }
     }
 
+    /// Number part of the game, such that `self = number_part + infinitesimal_part`
+    ///
+    /// If game is infinitesimally close to a number, i.e. both stops are equal, it is that number.
+    /// Otherwise there is no clean split and [mean](Self::mean) is used, so for `{2|0}` number
+    /// part is `1` and infinitesimal part is `{1|-1}`.
+    pub fn number_part(&self) -> Self {
+        Self::new_dyadic(self.mean())
+    }
+
+    /// Infinitesimal part of the game, `self - number_part`. See [`CanonicalForm::number_part`]
+    pub fn infinitesimal_part(&self) -> Self {
+        self - self.number_part()
+    }
+
     /// Cool the position by `temperature`
     ///
     /// Position `G` cooled by `t` is `G_t = {G^L_t - t | G^R_t + t}` unless there exists a
@@ -1209,6 +1835,54 @@ impl CanonicalForm {
         new_moves.canonical_form()
     }
 
+    /// Chill the position, i.e. cool it by `1`. Used in Berlekamp's analysis of games like
+    /// Dots-and-Boxes and Blockbusting, where chilled values are usually much simpler. Odd
+    /// integers plus star, e.g. `1*`, chill to the integer. See [`CanonicalForm::warm`] for the
+    /// inverse.
+    #[must_use]
+    pub fn chill(&self) -> Self {
+        self.cool(DyadicRationalNumber::from(1))
+    }
+
+    /// Warm the position, the inverse of [`CanonicalForm::chill`] for even and odd games.
+    ///
+    /// Warming is defined as `∫G = G` if `G` is an even integer, `∫G = G*` if `G` is an odd
+    /// integer, and `∫G = {1* + ∫G^L | -1* + ∫G^R}` otherwise. Unlike heating by `1`, the
+    /// stars keep track of the parity of moves that chilling forgets.
+    #[must_use]
+    pub fn warm(&self) -> Self {
+        if let Some(integer) = self.to_nus().and_then(|nus| {
+            if nus.is_integer() {
+                nus.number().to_integer()
+            } else {
+                None
+            }
+        }) {
+            return if integer % 2 == 0 {
+                self.clone()
+            } else {
+                self + Self::new_nimber(DyadicRationalNumber::from(0), Nimber::new(1))
+            };
+        }
+
+        let one_star = Self::new_nimber(DyadicRationalNumber::from(1), Nimber::new(1));
+        let moves = self.to_moves();
+        let new_moves = Moves {
+            left: moves
+                .left
+                .iter()
+                .map(|left_move| left_move.warm() + &one_star)
+                .collect(),
+            right: moves
+                .right
+                .iter()
+                .map(|right_move| right_move.warm() - &one_star)
+                .collect(),
+        };
+
+        new_moves.canonical_form()
+    }
+
     /// A remote star of game `g` is a nimber `*N` if no position of `g` including `g` has value `N*`
     #[must_use]
     #[cfg_attr(feature = "cargo-clippy", allow(clippy::or_fun_call))]
@@ -1300,14 +1974,113 @@ impl CanonicalForm {
         }
     }
 
-    /// Parse game using `{a,b,...|c,d,...}` notation
-    #[cfg_attr(feature = "cargo-clippy", allow(clippy::missing_errors_doc))]
-    pub fn parse(input: &str) -> nom::IResult<&str, Self> {
+    /// Encode game as a compact sequence of bytes, independent of serde. Options are written in
+    /// postorder, every NUS takes 21 bytes and every other node takes 9 bytes. Use
+    /// [`Self::from_bytes`] to decode.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        self.write_bytes(&mut buf);
+        buf
+    }
+
+    fn write_bytes(&self, buf: &mut Vec<u8>) {
+        match &self.inner {
+            CanonicalFormInner::Nus(nus) => {
+                buf.push(BYTES_NUS_TAG);
+                buf.extend_from_slice(&nus.number.numerator().to_le_bytes());
+                buf.extend_from_slice(&nus.number.denominator_exponent().to_le_bytes());
+                buf.extend_from_slice(&nus.up_multiple.to_le_bytes());
+                buf.extend_from_slice(&nus.nimber.value().to_le_bytes());
+            }
+            CanonicalFormInner::Moves(moves) => {
+                for m in moves.left.iter().chain(moves.right.iter()) {
+                    m.write_bytes(buf);
+                }
+                buf.push(BYTES_MOVES_TAG);
+                buf.extend_from_slice(&(moves.left.len() as u32).to_le_bytes());
+                buf.extend_from_slice(&(moves.right.len() as u32).to_le_bytes());
+            }
+        }
+    }
+
+    /// Decode game encoded with [`Self::to_bytes`]. Returns [None] if input is malformed.
+    ///
+    /// Options are canonicalized again, so input that is well-formed but not in canonical form
+    /// still yields a game in canonical form.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        fn take<const N: usize>(bytes: &mut &[u8]) -> Option<[u8; N]> {
+            if bytes.len() < N {
+                return None;
+            }
+            let (taken, rest) = bytes.split_at(N);
+            *bytes = rest;
+            taken.try_into().ok()
+        }
+
+        let mut bytes = bytes;
+        let mut stack: Vec<Self> = Vec::new();
+
+        while let Some([tag]) = take::<1>(&mut bytes) {
+            match tag {
+                BYTES_NUS_TAG => {
+                    let numerator = i64::from_le_bytes(take(&mut bytes)?);
+                    let denominator_exponent = u32::from_le_bytes(take(&mut bytes)?);
+                    if denominator_exponent >= i64::BITS - 1 {
+                        return None;
+                    }
+                    let up_multiple = i32::from_le_bytes(take(&mut bytes)?);
+                    let nimber = u32::from_le_bytes(take(&mut bytes)?);
+                    stack.push(Self::new_nus(Nus::new(
+                        DyadicRationalNumber::new(numerator, denominator_exponent),
+                        up_multiple,
+                        Nimber::new(nimber),
+                    )));
+                }
+                BYTES_MOVES_TAG => {
+                    let left = u32::from_le_bytes(take(&mut bytes)?) as usize;
+                    let right = u32::from_le_bytes(take(&mut bytes)?) as usize;
+                    let options = stack.len().checked_sub(left.checked_add(right)?)?;
+                    let mut left = stack.split_off(options);
+                    let right = left.split_off(left.len() - right);
+                    stack.push(Self::new_from_moves(Moves { left, right }));
+                }
+                _ => return None,
+            }
+        }
+
+        match stack.as_slice() {
+            [_] => stack.pop(),
+            _ => None,
+        }
+    }
+
+    /// Parse single game using `{a,b,...|c,d,...}` notation or NUS notation
+    fn parse_term(input: &str) -> nom::IResult<&str, Self> {
         alt((
             |input| Nus::parse(input).map(|(input, nus)| (input, Self::new_nus(nus))),
             |input| Moves::parse(input).map(|(input, moves)| (input, Self::new_from_moves(moves))),
         ))(input)
     }
+
+    /// Parse game using `{a,b,...|c,d,...}` notation. Games can be added or subtracted
+    /// with `+` and `-`, e.g. `2 + * - {1|0}`
+    #[cfg_attr(feature = "cargo-clippy", allow(clippy::missing_errors_doc))]
+    pub fn parse(input: &str) -> nom::IResult<&str, Self> {
+        let (input, first) = Self::parse_term(input)?;
+        fold_many0(
+            pair(lexeme(one_of("+-")), Self::parse_term),
+            move || first.clone(),
+            |acc, (op, term)| if op == '+' { acc + term } else { acc - term },
+        )(input)
+    }
+}
+
+impl From<Nimber> for CanonicalForm {
+    /// Construct star game `*n` with options `*0..*n` for both players
+    #[inline]
+    fn from(nimber: Nimber) -> Self {
+        Self::new_nus(Nus::new_nimber(nimber))
+    }
 }
 
 impl PartialOrd for CanonicalForm {
@@ -1334,6 +2107,24 @@ impl PartialOrd for CanonicalForm {
 }
 
 impl_op_ex!(+|g: &CanonicalForm, h: &CanonicalForm| -> CanonicalForm { CanonicalForm::construct_sum(g, h) });
+
+impl Sum for CanonicalForm {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(constants::zero(), |acc, g| acc + g)
+    }
+}
+
+impl<'a> Sum<&'a CanonicalForm> for CanonicalForm {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(constants::zero(), |acc, g| acc + g)
+    }
+}
 impl_op_ex!(+=|g: &mut CanonicalForm, h: &CanonicalForm| { *g = CanonicalForm::construct_sum(g, h) });
 impl_op_ex!(-|g: &CanonicalForm| -> CanonicalForm { CanonicalForm::construct_negative(g) });
 impl_op_ex!(-|g: &CanonicalForm, h: &CanonicalForm| -> CanonicalForm {
@@ -1358,7 +2149,7 @@ impl_from_str_via_nom!(CanonicalForm);
 mod tests {
     use super::*;
     use quickcheck::{Arbitrary, Gen, QuickCheck};
-    use std::{ops::Neg, str::FromStr};
+    use std::{collections::HashMap, ops::Neg, str::FromStr};
 
     macro_rules! parse_nus_roundtrip {
         ($inp: expr) => {
@@ -1498,6 +2289,13 @@ mod tests {
         let star_three_moves = star_three.to_moves();
         assert_eq!(star_three_moves.to_string(), "{0, *, *2|0, *, *2}");
 
+        let star_three = CanonicalForm::from(Nimber::new(3));
+        assert_eq!(&star_three.to_string(), "*3");
+        assert_eq!(
+            star_three,
+            CanonicalForm::from(Nimber::new(1)) + CanonicalForm::from(Nimber::new(2))
+        );
+
         let one_star_two = CanonicalForm::new_nus(Nus {
             number: DyadicRationalNumber::from(1),
             up_multiple: 0,
@@ -1544,7 +2342,8 @@ mod tests {
         assert_negative_eq!("0", "0");
         assert_negative_eq!("42", "-42");
         assert_negative_eq!("-42", "42");
-        assert_negative_eq!("{^|*}", "{v|*}");
+        assert_negative_eq!("{^|*}", "{*|v}");
+        assert_negative_eq!("{3|{1|0}}", "{{0|-1}|-3}");
     }
 
     #[test]
@@ -1649,6 +2448,31 @@ mod tests {
         assert_eq!(&sum.to_string(), "{3/2|1/2}");
     }
 
+    #[test]
+    fn assign_ops_accumulate() {
+        let games = ["1", "*", "{1|0}", "^", "-1/2"]
+            .into_iter()
+            .map(|game| CanonicalForm::from_str(game).unwrap())
+            .collect::<Vec<_>>();
+
+        let sum = games
+            .iter()
+            .fold(CanonicalForm::new_integer(0), |mut acc, game| {
+                acc += game;
+                acc
+            });
+        assert_eq!(
+            sum,
+            CanonicalForm::from_str("1 + * + {1|0} + ^ - 1/2").unwrap()
+        );
+
+        let mut difference = sum;
+        for game in &games {
+            difference -= game;
+        }
+        assert_eq!(difference, CanonicalForm::new_integer(0));
+    }
+
     #[test]
     fn temp_of_one_minus_one_is_one() {
         let one = CanonicalForm::new_integer(1);
@@ -1660,6 +2484,153 @@ mod tests {
         };
         let g = CanonicalForm::new_from_moves(moves);
         assert_eq!(g.temperature(), DyadicRationalNumber::from(1));
+        assert_eq!(g.to_string_with_temperature(), "{1|-1} (t=1)");
+
+        let three_quarters = CanonicalForm::new_dyadic(DyadicRationalNumber::new(3, 2));
+        assert_eq!(three_quarters.to_string_with_temperature(), "3/4 (t=-1/4)");
+    }
+
+    #[test]
+    fn negative_of_deep_game() {
+        let mut game = CanonicalForm::from_str("{1|-1}").unwrap();
+        for k in 2..100 {
+            game = CanonicalForm::new_from_moves(
+                Moves::builder()
+                    .left(CanonicalForm::new_integer(k))
+                    .right(game)
+                    .build(),
+            );
+        }
+        assert!(!game.is_number_up_star());
+
+        let negative = -&game;
+        assert_eq!(negative.left_stop(), -game.right_stop());
+        assert_eq!(negative.right_stop(), -game.left_stop());
+        assert_eq!(-&negative, game);
+    }
+
+    #[test]
+    fn sum_with_zero() {
+        let zero = CanonicalForm::new_integer(0);
+        assert!(zero.is_zero());
+        assert!(!CanonicalForm::from_str("*").unwrap().is_zero());
+        assert!(!CanonicalForm::from_str("{1|-1}").unwrap().is_zero());
+
+        let mut game = CanonicalForm::from_str("{1|-1}").unwrap();
+        for k in 2..100 {
+            game = CanonicalForm::new_from_moves(
+                Moves::builder()
+                    .left(CanonicalForm::new_integer(k))
+                    .right(game)
+                    .build(),
+            );
+        }
+        assert_eq!(&zero + &game, game);
+        assert_eq!(&game + &zero, game);
+        assert_eq!(&zero + &zero, zero);
+    }
+
+    #[test]
+    fn switch_roundtrip() {
+        for (n, expected) in [("1", "{1|-1}"), ("3/2", "{3/2|-3/2}"), ("0", "*")] {
+            let n = DyadicRationalNumber::from_str(n).unwrap();
+            let switch = CanonicalForm::switch(&n);
+            assert_eq!(switch.to_string(), expected);
+            assert_eq!(switch.as_switch(), Some(n));
+        }
+
+        for game in ["0", "1", "*2", "{2|-1}", "{1|-2}", "{1|-1*}", "^"] {
+            let game = CanonicalForm::from_str(game).unwrap();
+            assert_eq!(game.as_switch(), None, "{game}");
+        }
+    }
+
+    #[test]
+    fn from_options_works() {
+        let one = CanonicalForm::new_integer(1);
+        let minus_one = CanonicalForm::new_integer(-1);
+        let expected = CanonicalForm::from_str("{1|-1}").unwrap();
+        assert_eq!(
+            CanonicalForm::from_options(&[one.clone(), one.clone()], &[-&one]),
+            expected
+        );
+
+        // Dominated options are removed
+        let zero = CanonicalForm::new_integer(0);
+        assert_eq!(
+            CanonicalForm::from_options(&[zero, one], &[minus_one]),
+            expected
+        );
+        assert_eq!(
+            CanonicalForm::from_options(&[], &[]),
+            CanonicalForm::new_integer(0)
+        );
+    }
+
+    #[test]
+    fn sum_of_iterator() {
+        let games = ["1", "*", "-1"]
+            .into_iter()
+            .map(|game| CanonicalForm::from_str(game).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(games.iter().sum::<CanonicalForm>(), constants::star());
+        assert_eq!(games.into_iter().sum::<CanonicalForm>(), constants::star());
+        assert_eq!(
+            std::iter::empty::<CanonicalForm>().sum::<CanonicalForm>(),
+            constants::zero()
+        );
+    }
+
+    #[test]
+    fn sum_temperature_works() {
+        let parse = |input| CanonicalForm::from_str(input).unwrap();
+        let check = |components: &[CanonicalForm]| {
+            let sum = components
+                .iter()
+                .fold(CanonicalForm::new_integer(0), |acc, component| {
+                    acc + component
+                });
+            let temperature = CanonicalForm::sum_temperature(components);
+            assert_eq!(temperature, sum.temperature());
+            temperature
+        };
+
+        // Numbers only
+        assert_eq!(check(&[]), DyadicRationalNumber::from(-1));
+        assert_eq!(
+            check(&[parse("1/2"), parse("1/4")]),
+            DyadicRationalNumber::new(-1, 2)
+        );
+        assert_eq!(
+            check(&[parse("1/2"), parse("1/2")]),
+            DyadicRationalNumber::from(-1)
+        );
+
+        // Single non-number, maximum of components
+        assert_eq!(
+            check(&[parse("{3|-1}"), parse("5/8"), parse("-2")]),
+            DyadicRationalNumber::from(2)
+        );
+
+        // All NUS
+        assert_eq!(
+            check(&[parse("*"), parse("*")]),
+            DyadicRationalNumber::from(-1)
+        );
+        assert_eq!(
+            check(&[parse("^"), parse("*"), parse("1")]),
+            DyadicRationalNumber::from(0)
+        );
+
+        // Full sum
+        assert_eq!(
+            check(&[parse("{1|-1}"), parse("{1|-1}")]),
+            DyadicRationalNumber::from(-1)
+        );
+        assert_eq!(
+            check(&[parse("{4|-4}"), parse("{1|-1}"), parse("*")]),
+            DyadicRationalNumber::from(4)
+        );
     }
 
     #[test]
@@ -1683,6 +2654,141 @@ mod tests {
         test_game_parse!("{0,*,*2|0,*,*2}", "*3");
     }
 
+    #[test]
+    fn moves_builder_matches_literal() {
+        let one = CanonicalForm::new_integer(1);
+        let star = CanonicalForm::from_str("*").unwrap();
+        let built = Moves::builder()
+            .left(one.clone())
+            .left(star.clone())
+            .right(star.clone())
+            .build();
+        assert_eq!(
+            built,
+            Moves {
+                left: vec![one, star.clone()],
+                right: vec![star.clone()],
+            }
+        );
+        assert_eq!(
+            Moves::builder().build(),
+            Moves {
+                left: vec![],
+                right: vec![],
+            }
+        );
+
+        let zero = CanonicalForm::new_integer(0);
+        let symmetric = Moves::symmetric(vec![zero.clone(), star.clone()]);
+        assert_eq!(
+            symmetric,
+            Moves {
+                left: vec![zero.clone(), star.clone()],
+                right: vec![zero, star],
+            }
+        );
+        assert_eq!(CanonicalForm::new_from_moves(symmetric).to_string(), "*2");
+    }
+
+    #[test]
+    fn up_multiple_works() {
+        let up_multiple = |input| CanonicalForm::from_str(input).unwrap().up_multiple();
+        assert_eq!(up_multiple("^"), Some((1, false)));
+        assert_eq!(up_multiple("^*"), Some((1, true)));
+        assert_eq!(up_multiple("^2"), Some((2, false)));
+        assert_eq!(up_multiple("v3*"), Some((-3, true)));
+        assert_eq!(up_multiple("{0|^*}"), Some((2, false)));
+        assert_eq!(up_multiple("*"), Some((0, true)));
+        assert_eq!(up_multiple("0"), Some((0, false)));
+        assert_eq!(up_multiple("^*2"), None);
+        assert_eq!(up_multiple("1^"), None);
+        assert_eq!(up_multiple("{1|-1}"), None);
+    }
+
+    #[cfg(feature = "statistics")]
+    #[test]
+    fn simplification_stats() {
+        let before = CanonicalForm::simplification_stats();
+        let game = CanonicalForm::new_from_moves(
+            Moves::builder()
+                .left(CanonicalForm::new_integer(0))
+                .left(CanonicalForm::new_integer(1))
+                .right(CanonicalForm::new_integer(3))
+                .build(),
+        );
+        assert_eq!(game, CanonicalForm::new_integer(2));
+        let after = CanonicalForm::simplification_stats();
+        assert!(after.dominated_removed > before.dominated_removed);
+
+        let before = CanonicalForm::simplification_stats();
+        let game = CanonicalForm::new_from_moves(
+            Moves::builder()
+                .left(CanonicalForm::new_nimber(
+                    DyadicRationalNumber::from(0),
+                    Nimber::new(1),
+                ))
+                .build(),
+        );
+        assert_eq!(game, CanonicalForm::new_integer(0));
+        let after = CanonicalForm::simplification_stats();
+        assert!(after.reversible_bypassed > before.reversible_bypassed);
+    }
+
+    #[test]
+    fn one_sided_games() {
+        for (input, expected) in [
+            ("{0|}", "1"),
+            ("{|0}", "-1"),
+            ("{1/2|}", "1"),
+            ("{-3|}", "0"),
+            ("{*|}", "0"),
+            ("{|*}", "0"),
+            ("{^|}", "1"),
+            ("{{5|-3}|}", "0"),
+            ("{|{3|1}}", "0"),
+            ("{3/4, *|}", "1"),
+        ] {
+            assert_eq!(
+                CanonicalForm::from_str(input).unwrap().to_string(),
+                expected,
+                "{input}"
+            );
+        }
+
+        let one = CanonicalForm::new_from_moves(Moves {
+            left: vec![CanonicalForm::new_integer(0)],
+            right: vec![],
+        });
+        assert_eq!(one, CanonicalForm::new_integer(1));
+
+        let zero = CanonicalForm::new_from_moves(Moves {
+            left: vec![CanonicalForm::new_nimber(
+                DyadicRationalNumber::from(0),
+                Nimber::new(1),
+            )],
+            right: vec![],
+        });
+        assert!(zero.is_zero());
+    }
+
+    #[test]
+    fn parse_sums() {
+        macro_rules! test_sum_parse {
+            ($inp: expr, $expected: expr) => {{
+                let g = CanonicalForm::from_str($inp).expect("Could not parse");
+                assert_eq!($expected, g.to_string());
+                assert_eq!(g, CanonicalForm::from_str(&g.to_string()).unwrap());
+            }};
+        }
+
+        test_sum_parse!("1 + *", "1*");
+        test_sum_parse!("2 + * + {1|0}", "{3*|2*}");
+        test_sum_parse!("^ - ^", "0");
+        test_sum_parse!("1/2-1", "-1/2");
+        test_sum_parse!("{1 + 1|-1 - *}", "{2|-1*}");
+        assert!(CanonicalForm::from_str("1 +").is_err());
+    }
+
     #[test]
     fn ordering_works() {
         macro_rules! test_ordering {
@@ -1734,6 +2840,7 @@ mod tests {
         assert_stops!("v", "0", "0");
         assert_stops!("*", "0", "0");
         assert_stops!("^", "0", "0");
+        assert_stops!("{10|{0|-10},{5|-100}}", "10", "0");
     }
 
     macro_rules! assert_cooled {
@@ -1778,6 +2885,284 @@ mod tests {
         assert_eq!(g, &frozen + &heated);
     }
 
+    #[test]
+    fn chilling_and_warming() {
+        for (input, chilled) in [
+            // 2x2 Domineering
+            ("{1|-1}", "*"),
+            ("{2|-2}", "{1|-1}"),
+            ("{1*|0}", "1/2"),
+            ("3*", "3"),
+            ("4", "4"),
+            ("{3|{1|-1}}", "{2|1*}"),
+        ] {
+            let g = CanonicalForm::from_str(input).unwrap();
+            assert_eq!(g.chill().to_string(), chilled, "{input}");
+        }
+
+        for (input, warmed) in [
+            ("0", "0"),
+            ("1", "1*"),
+            ("-2", "-2"),
+            ("*", "{1*|-1*}"),
+            ("1/2", "{1*|0}"),
+        ] {
+            let g = CanonicalForm::from_str(input).unwrap();
+            assert_eq!(g.warm().to_string(), warmed, "{input}");
+            assert_eq!(g.warm().chill(), g, "{input}");
+        }
+    }
+
+    fn arbitrary_small_game(g: &mut Gen, depth: u8) -> CanonicalForm {
+        if depth == 0 || u8::arbitrary(g) % 3 == 0 {
+            let leaf = *g
+                .choose(&["0", "1", "-2", "1/2", "-3/4", "*", "*2", "^", "v*", "^2*3"])
+                .unwrap();
+            return CanonicalForm::from_str(leaf).unwrap();
+        }
+
+        let mut moves = Moves::empty();
+        for _ in 0..(u8::arbitrary(g) % 3) {
+            moves.left.push(arbitrary_small_game(g, depth - 1));
+        }
+        for _ in 0..(u8::arbitrary(g) % 3) {
+            moves.right.push(arbitrary_small_game(g, depth - 1));
+        }
+        CanonicalForm::new_from_moves(moves)
+    }
+
+    #[test]
+    fn bytes_roundtrip() {
+        let mut g = Gen::new(100);
+        for _ in 0..500 {
+            let game = arbitrary_small_game(&mut g, 3);
+            let bytes = game.to_bytes();
+            assert_eq!(CanonicalForm::from_bytes(&bytes), Some(game.clone()));
+        }
+
+        let game = CanonicalForm::from_str("{3|{1|0}}").unwrap();
+        let bytes = game.to_bytes();
+        assert_eq!(CanonicalForm::from_bytes(&bytes[..bytes.len() - 1]), None);
+        assert_eq!(
+            CanonicalForm::from_bytes(&[bytes.clone(), bytes].concat()),
+            None
+        );
+        assert_eq!(CanonicalForm::from_bytes(&[]), None);
+
+        // {0|0} is not in canonical form
+        let zero = CanonicalForm::new_integer(0).to_bytes();
+        let mut bytes = [zero.clone(), zero].concat();
+        bytes.push(BYTES_MOVES_TAG);
+        bytes.extend_from_slice(&1u32.to_le_bytes());
+        bytes.extend_from_slice(&1u32.to_le_bytes());
+        assert_eq!(
+            CanonicalForm::from_bytes(&bytes),
+            Some(CanonicalForm::from_str("*").unwrap())
+        );
+
+        // Denominator 2^63 does not fit
+        let mut bytes = vec![BYTES_NUS_TAG];
+        bytes.extend_from_slice(&1i64.to_le_bytes());
+        bytes.extend_from_slice(&63u32.to_le_bytes());
+        bytes.extend_from_slice(&0i32.to_le_bytes());
+        bytes.extend_from_slice(&0u32.to_le_bytes());
+        assert_eq!(CanonicalForm::from_bytes(&bytes), None);
+    }
+
+    #[test]
+    fn nimber_difference_works() {
+        let cf = |s| CanonicalForm::from_str(s).unwrap();
+        assert_eq!(cf("1*2").nimber_difference(&cf("1")), Some(Nimber::new(2)));
+        assert_eq!(
+            cf("1*2").nimber_difference(&cf("1*3")),
+            Some(Nimber::new(1))
+        );
+        assert_eq!(
+            cf("{1|0}").nimber_difference(&cf("{1|0}")),
+            Some(Nimber::new(0))
+        );
+        assert_eq!(cf("^*").nimber_difference(&cf("*")), None);
+        assert_eq!(cf("{1|0}").nimber_difference(&cf("*")), None);
+    }
+
+    #[test]
+    fn cmp_numbers_works() {
+        let cf = |s| CanonicalForm::from_str(s).unwrap();
+        let mut numbers = vec![cf("1/2"), cf("-1"), cf("3/4"), cf("2")];
+        numbers.sort_by(|lhs, rhs| lhs.cmp_numbers(rhs).unwrap());
+        assert_eq!(numbers, vec![cf("-1"), cf("1/2"), cf("3/4"), cf("2")]);
+
+        assert_eq!(cf("1").cmp_numbers(&cf("1")), Some(Ordering::Equal));
+        assert_eq!(cf("1").cmp_numbers(&cf("*")), None);
+        assert_eq!(cf("{1|-1}").cmp_numbers(&cf("0")), None);
+    }
+
+    #[test]
+    fn is_infinitesimally_close_to_works() {
+        let cf = |s| CanonicalForm::from_str(s).unwrap();
+        assert!(cf("1*").is_infinitesimally_close_to(&cf("1")));
+        assert!(cf("1").is_infinitesimally_close_to(&cf("1")));
+        assert!(cf("^").is_infinitesimally_close_to(&cf("0")));
+        assert!(!cf("2").is_infinitesimally_close_to(&cf("1")));
+        assert!(!cf("1/64").is_infinitesimally_close_to(&cf("0")));
+        assert!(!cf("{1|-1}").is_infinitesimally_close_to(&cf("0")));
+    }
+
+    #[test]
+    fn born_by_works() {
+        assert_eq!(
+            CanonicalForm::born_by(0).collect::<Vec<_>>(),
+            vec![CanonicalForm::new_integer(0)]
+        );
+        assert_eq!(CanonicalForm::born_by(1).count(), 4);
+
+        let day_two = CanonicalForm::born_by(2).collect::<Vec<_>>();
+        assert_eq!(day_two.len(), 22);
+        for game in CanonicalForm::born_by(1) {
+            assert!(day_two.contains(&game));
+        }
+        assert!(day_two.contains(&CanonicalForm::from_str("{1|-1}").unwrap()));
+        assert!(day_two.contains(&CanonicalForm::from_str("^*").unwrap()));
+
+        assert_eq!(CanonicalForm::born_by(3).count(), 1474);
+    }
+
+    #[test]
+    fn sum_identities_hold() {
+        for game in [
+            "0",
+            "*",
+            "^",
+            "{1|-1}",
+            "{3|{1|0}}",
+            "{{2|1}|{0|-1},-3}",
+            "1/2*",
+        ] {
+            let game = CanonicalForm::from_str(game).unwrap();
+            game.verify_identities();
+            (&game + &game).verify_identities();
+        }
+        for game in CanonicalForm::born_by(2) {
+            game.verify_identities();
+        }
+
+        // Memoized sum used by the check agrees with the regular one
+        let mut game = CanonicalForm::from_str("{1|-1}").unwrap();
+        for k in 2..8 {
+            game = CanonicalForm::new_from_moves(
+                Moves::builder()
+                    .left(CanonicalForm::new_integer(k))
+                    .right(game)
+                    .build(),
+            );
+        }
+        let other = CanonicalForm::from_str("{{2|1}|{0|-1},-3}").unwrap();
+        assert_eq!(
+            CanonicalForm::construct_sum_memoized(&game, &other, &mut HashMap::new()),
+            CanonicalForm::construct_sum_unverified(&game, &other)
+        );
+    }
+
+    #[test]
+    fn tiny_miny_work() {
+        let cf = |s| CanonicalForm::from_str(s).unwrap();
+        let tiny_one = CanonicalForm::tiny(&cf("1"));
+        assert_eq!(tiny_one, cf("{0|{0|-1}}"));
+        assert!(tiny_one > cf("0"));
+        for positive in ["1", "1/2", "1/64", "1/1024"] {
+            assert!(tiny_one < cf(positive));
+        }
+        assert!(tiny_one < cf("^"));
+
+        for g in ["1", "2", "{1|-1}", "1/2"] {
+            assert_eq!(CanonicalForm::tiny(&cf(g)), -CanonicalForm::miny(&cf(g)));
+        }
+        assert_eq!(CanonicalForm::miny(&cf("2")), cf("{{2|0}|0}"));
+    }
+
+    #[test]
+    fn star_count_works() {
+        let cf = |s| CanonicalForm::from_str(s).unwrap();
+        assert_eq!(cf("*").star_count(), Some(1));
+        assert_eq!(cf("*3").star_count(), Some(3));
+        assert_eq!(cf("0").star_count(), Some(0));
+        assert_eq!(cf("{0,*|0,*}").star_count(), Some(2));
+        assert_eq!(cf("^").star_count(), None);
+        assert_eq!(cf("1*").star_count(), None);
+        assert_eq!(cf("{1|-1}").star_count(), None);
+    }
+
+    #[test]
+    fn explain_reduction_works() {
+        let cf = |s| CanonicalForm::from_str(s).unwrap();
+
+        let report = CanonicalForm::explain_reduction(Moves::from_str("{0, 1 | 0}").unwrap());
+        assert_eq!(
+            report.left,
+            vec![
+                (cf("0"), Reduction::Dominated(cf("1"))),
+                (cf("1"), Reduction::Kept)
+            ]
+        );
+        assert_eq!(report.right, vec![(cf("0"), Reduction::Kept)]);
+        assert_eq!(report.canonical_form, cf("{1|0}"));
+
+        let report = CanonicalForm::explain_reduction(Moves::from_str("{* | *}").unwrap());
+        assert_eq!(report.left, vec![(cf("*"), Reduction::Reversible(cf("0")))]);
+        assert_eq!(
+            report.right,
+            vec![(cf("*"), Reduction::Reversible(cf("0")))]
+        );
+        assert_eq!(report.canonical_form, cf("0"));
+
+        let report = CanonicalForm::explain_reduction(Moves::from_str("{1, 1 |}").unwrap());
+        assert_eq!(
+            report.left,
+            vec![
+                (cf("1"), Reduction::Kept),
+                (cf("1"), Reduction::Dominated(cf("1")))
+            ]
+        );
+    }
+
+    #[test]
+    fn number_infinitesimal_split() {
+        let cf = |s| CanonicalForm::from_str(s).unwrap();
+        for (game, number, infinitesimal) in [
+            ("1*", "1", "*"),
+            ("3^*", "3", "^*"),
+            ("{1|1*}", "1", "{0|*}"),
+            ("-5/4", "-5/4", "0"),
+            ("{2|0}", "1", "{1|-1}"),
+        ] {
+            let game = cf(game);
+            assert_eq!(game.number_part(), cf(number));
+            assert_eq!(game.infinitesimal_part(), cf(infinitesimal));
+            assert_eq!(game.number_part() + game.infinitesimal_part(), game);
+        }
+    }
+
+    #[test]
+    fn write_to_matches_display() {
+        let mut buf = String::from("G = ");
+        for game in ["0", "{1|-1}", "3/4^*2", "{{2|0}|-1}"] {
+            let game = CanonicalForm::from_str(game).unwrap();
+            buf.truncate(4);
+            game.write_to(&mut buf).unwrap();
+            assert_eq!(buf, format!("G = {game}"));
+        }
+    }
+
+    #[test]
+    fn thermal_class_works() {
+        let cf = |s| CanonicalForm::from_str(s).unwrap();
+        assert_eq!(cf("{1|-1}").thermal_class(), ThermalClass::Hot);
+        assert_eq!(cf("*").thermal_class(), ThermalClass::Tepid);
+        assert_eq!(cf("^").thermal_class(), ThermalClass::Tepid);
+        assert_eq!(cf("3").thermal_class(), ThermalClass::Cold);
+        assert_eq!(cf("1/2").thermal_class(), ThermalClass::Cold);
+    }
+
     macro_rules! assert_atomic_weight_eq {
         ($inp:expr, $atomic:expr) => {
             let cf = CanonicalForm::from_str($inp).unwrap();
@@ -0,0 +1,71 @@
+//! Frequently used canonical forms. All of them are number-up-star sums, so they are constructed
+//! without allocation or parsing.
+
+use crate::{
+    numeric::{dyadic_rational_number::DyadicRationalNumber, nimber::Nimber},
+    short::partizan::canonical_form::{CanonicalForm, Nus},
+};
+
+/// Zero game `{|}`
+#[inline]
+pub const fn zero() -> CanonicalForm {
+    CanonicalForm::new_integer(0)
+}
+
+/// Star game `{0|0}`
+#[inline]
+pub const fn star() -> CanonicalForm {
+    CanonicalForm::new_nus(Nus::new_nimber(Nimber::new(1)))
+}
+
+/// Up game `{0|*}`
+#[inline]
+pub const fn up() -> CanonicalForm {
+    CanonicalForm::new_nus(Nus::new(
+        DyadicRationalNumber::new_integer(0),
+        1,
+        Nimber::new(0),
+    ))
+}
+
+/// Down game `{*|0}`
+#[inline]
+pub const fn down() -> CanonicalForm {
+    CanonicalForm::new_nus(Nus::new(
+        DyadicRationalNumber::new_integer(0),
+        -1,
+        Nimber::new(0),
+    ))
+}
+
+/// Up star game `{0,*|0}`
+#[inline]
+pub const fn up_star() -> CanonicalForm {
+    CanonicalForm::new_nus(Nus::new(
+        DyadicRationalNumber::new_integer(0),
+        1,
+        Nimber::new(1),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn constants_match_parsed() {
+        assert_eq!(zero(), CanonicalForm::from_str("{|}").unwrap());
+        assert_eq!(star(), CanonicalForm::from_str("{0|0}").unwrap());
+        assert_eq!(up(), CanonicalForm::from_str("{0|*}").unwrap());
+        assert_eq!(down(), CanonicalForm::from_str("{*|0}").unwrap());
+        assert_eq!(up_star(), CanonicalForm::from_str("{0,*|0}").unwrap());
+    }
+
+    #[test]
+    fn constants_sums() {
+        assert_eq!(up() + down(), zero());
+        assert_eq!(star() + star(), zero());
+        assert_eq!(up() + star(), up_star());
+    }
+}
@@ -3,6 +3,7 @@
 pub mod amazons;
 pub mod domineering;
 pub mod fission;
+pub mod hackenbush;
 pub mod ski_jumps;
 pub mod snort;
 pub mod toads_and_frogs;
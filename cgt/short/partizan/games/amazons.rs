@@ -1,8 +1,8 @@
 //! Amazons game
 
 use crate::{
-    grid::{decompositions, move_top_left, vec_grid::VecGrid, FiniteGrid, Grid},
-    short::partizan::partizan_game::PartizanGame,
+    grid::{decompositions, move_top_left, vec_grid::VecGrid, FiniteGrid, Grid, GridParseError},
+    short::partizan::partizan_game::{MoveList, PartizanGame},
 };
 use cgt_derive::Tile;
 use std::{fmt::Display, hash::Hash, str::FromStr};
@@ -55,10 +55,10 @@ impl<G> FromStr for Amazons<G>
 where
     G: Grid<Item = Tile> + FiniteGrid,
 {
-    type Err = ();
+    type Err = GridParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self::new(G::parse(s).ok_or(())?))
+        Ok(Self::new(G::parse(s)?))
     }
 }
 
@@ -83,13 +83,13 @@ where
         Self { grid }
     }
 
-    fn moves_for(&self, own_amazon: Tile) -> Vec<Self>
+    fn moves_for(&self, own_amazon: Tile) -> MoveList<Self>
     where
         G: Clone + PartialEq,
     {
         let longer_side = self.grid.height().max(self.grid.width());
 
-        let mut moves = Vec::new();
+        let mut moves = MoveList::new();
         for y in 0..self.grid.height() as i32 {
             for x in 0..self.grid.width() as i32 {
                 if self.grid.get(x as u8, y as u8) == own_amazon {
@@ -144,11 +144,11 @@ impl<G> PartizanGame for Amazons<G>
 where
     G: Grid<Item = Tile> + FiniteGrid + Clone + Hash + Send + Sync + Eq,
 {
-    fn left_moves(&self) -> Vec<Self> {
+    fn left_moves(&self) -> MoveList<Self> {
         self.moves_for(Tile::Left)
     }
 
-    fn right_moves(&self) -> Vec<Self> {
+    fn right_moves(&self) -> MoveList<Self> {
         self.moves_for(Tile::Right)
     }
 
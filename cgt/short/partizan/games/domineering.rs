@@ -4,12 +4,26 @@
 extern crate alloc;
 use crate::{
     drawing::svg::{self, ImmSvg, Svg},
-    grid::{decompositions, move_top_left, small_bit_grid::SmallBitGrid, FiniteGrid, Grid},
-    short::partizan::partizan_game::PartizanGame,
+    grid::{
+        decompositions, move_top_left, small_bit_grid::SmallBitGrid, BitTile, FiniteGrid, Grid,
+        GridParseError,
+    },
+    numeric::rational::Rational,
+    short::partizan::{
+        canonical_form::CanonicalForm,
+        partizan_game::{GridEnumerable, MoveList, PartizanGame},
+        symmetry::Symmetry,
+        transposition_table::TranspositionTable,
+    },
 };
 use cgt_derive::Tile;
 use core::{fmt, hash::Hash};
-use std::{fmt::Display, str::FromStr};
+use itertools::Either;
+use rand::Rng;
+use std::{fmt::Display, iter, str::FromStr};
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::{IntoParallelIterator, ParallelIterator};
 
 /// Tile on a Domineering grid
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Tile)]
@@ -31,11 +45,39 @@ impl Tile {
     }
 }
 
+/// Shape of the board on which dominoes are placed
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Topology {
+    /// Regular rectangular board
+    #[default]
+    Flat,
+
+    /// Board where opposite edges are connected, so dominoes can wrap around
+    Torus,
+}
+
+/// Orientation of dominoes placed by each player
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Convention {
+    /// Left places vertical dominoes, Right places horizontal dominoes
+    #[default]
+    LeftVertical,
+
+    /// Left places horizontal dominoes, Right places vertical dominoes
+    LeftHorizontal,
+}
+
 /// A Domineering position on a rectengular grid.
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Domineering<G = SmallBitGrid<Tile>> {
     grid: G,
+    #[cfg_attr(feature = "serde", serde(default))]
+    topology: Topology,
+    #[cfg_attr(feature = "serde", serde(default))]
+    convention: Convention,
 }
 
 impl<G> Display for Domineering<G>
@@ -51,10 +93,10 @@ impl<G> FromStr for Domineering<G>
 where
     G: Grid<Item = Tile> + FiniteGrid,
 {
-    type Err = ();
+    type Err = GridParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self::new(G::parse(s).ok_or(())?))
+        Ok(Self::new(G::parse(s)?))
     }
 }
 
@@ -64,7 +106,68 @@ where
 {
     /// Create a domineering position from a grid.
     pub const fn new(grid: G) -> Self {
-        Self { grid }
+        Self {
+            grid,
+            topology: Topology::Flat,
+            convention: Convention::LeftVertical,
+        }
+    }
+
+    /// Parse position with rows separated by `row_separator`, e.g. `'\n'` for boards pasted from
+    /// a spreadsheet. With `'\n'` Windows line endings and trailing newlines are accepted.
+    ///
+    /// # Errors
+    /// See [`FiniteGrid::parse`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cgt::short::partizan::games::domineering::Domineering;
+    /// use std::str::FromStr;
+    ///
+    /// let position: Domineering = Domineering::parse_with_separator("..#\n.#.\n", '\n').unwrap();
+    /// assert_eq!(position, Domineering::from_str("..#|.#.").unwrap());
+    /// ```
+    pub fn parse_with_separator(input: &str, row_separator: char) -> Result<Self, GridParseError> {
+        if row_separator == '\n' {
+            let input = input.trim_end_matches(['\r', '\n']).replace("\r\n", "\n");
+            return Ok(Self::new(G::parse_with_separator(&input, row_separator)?));
+        }
+        Ok(Self::new(G::parse_with_separator(input, row_separator)?))
+    }
+
+    /// Create position on a different grid, keeping topology and convention
+    const fn with_grid(&self, grid: G) -> Self {
+        Self {
+            grid,
+            topology: self.topology,
+            convention: self.convention,
+        }
+    }
+
+    /// Change the board topology. Grid layout stays the same, only adjacency of tiles changes.
+    #[must_use]
+    pub fn with_topology(mut self, topology: Topology) -> Self {
+        self.topology = topology;
+        self
+    }
+
+    /// Get the board topology
+    pub const fn topology(&self) -> Topology {
+        self.topology
+    }
+
+    /// Change which player places vertical dominoes. Flipping the convention negates the value of
+    /// the position.
+    #[must_use]
+    pub fn with_convention(mut self, convention: Convention) -> Self {
+        self.convention = convention;
+        self
+    }
+
+    /// Get the domino orientation convention
+    pub const fn convention(&self) -> Convention {
+        self.convention
     }
 
     /// Get underlying grid
@@ -85,16 +188,30 @@ where
     /// Like [`Self::to_latex`] but allows to specify image scale. Scale must be positive
     ///
     /// # Panics
-    /// - `scale` is negative
+    /// - `scale` is zero or negative
     pub fn to_latex_with_scale(&self, scale: f32) -> String {
-        use std::fmt::Write;
+        assert!(scale > 0., "Scale must be positive");
+
+        self.to_latex_with_options(&format!("scale={scale}"))
+    }
+
+    /// Like [`Self::to_latex_with_scale`] but with separate horizontal and vertical scale, e.g. to
+    /// fit positions in table cells with non-square aspect ratio. Scales must be positive
+    ///
+    /// # Panics
+    /// - `scale_x` or `scale_y` is zero or negative
+    pub fn to_latex_with_scales(&self, scale_x: f32, scale_y: f32) -> String {
+        assert!(scale_x > 0. && scale_y > 0., "Scale must be positive");
 
-        assert!(scale >= 0., "Scale must be positive");
+        self.to_latex_with_options(&format!("xscale={scale_x},yscale={scale_y}"))
+    }
 
-        let scale = scale.to_string();
+    /// Output position as LaTeX `TikZ` picture with given picture options
+    fn to_latex_with_options(&self, options: &str) -> String {
+        use std::fmt::Write;
 
         let mut buf = String::new();
-        write!(buf, "\\begin{{tikzpicture}}[scale={}] ", scale).unwrap();
+        write!(buf, "\\begin{{tikzpicture}}[{}] ", options).unwrap();
         for y in 0..self.grid.height() {
             for x in 0..self.grid.width() {
                 if self.grid.get(x, y) == Tile::Taken {
@@ -120,6 +237,15 @@ where
         buf
     }
 
+    /// Output `position & $temperature$ ` cells of a LaTeX table row, as used by the `latex-table`
+    /// command of `cgt-cli`.
+    ///
+    /// # Panics
+    /// - `scale` is zero or negative
+    pub fn to_latex_row(&self, temperature: &Rational, scale: f32) -> String {
+        format!("{} & ${}$ ", self.to_latex_with_scale(scale), temperature)
+    }
+
     /// Get number of empty tiles on a grid
     pub fn free_places(&self) -> usize {
         let mut res = 0;
@@ -133,27 +259,266 @@ where
         res
     }
 
-    fn moves_for<const DIR_X: u8, const DIR_Y: u8>(&self) -> Vec<Self>
+    /// Upper bound of coordinates of the first tile of a domino. On a torus dominoes can also start
+    /// at the last row or column, unless that would place the domino on one tile or overlap a
+    /// domino not going through the edge.
+    const fn moves_range(&self, size: u8, dir: u8) -> u8 {
+        match self.topology {
+            Topology::Torus if size > 2 => size,
+            _ => size - dir,
+        }
+    }
+
+    /// Bit mask of first tiles of all possible dominoes, see [`FiniteGrid::pairs_mask`].
+    /// Dominoes wrapping around a torus are not representable, so [None] is returned for it.
+    fn placements_mask<const DIR_X: u8, const DIR_Y: u8>(&self) -> Option<u64> {
+        match self.topology {
+            Topology::Flat => self.grid.pairs_mask(DIR_X, DIR_Y, Tile::is_non_blocking),
+            Topology::Torus => None,
+        }
+    }
+
+    /// First tiles of all possible dominoes, row by row
+    fn placements_for<const DIR_X: u8, const DIR_Y: u8>(
+        &self,
+    ) -> impl Iterator<Item = (u8, u8)> + '_ {
+        let width = self.grid.width();
+        let height = self.grid.height();
+
+        if let Some(mut mask) = self.placements_mask::<DIR_X, DIR_Y>() {
+            Either::Left(iter::from_fn(move || {
+                if mask == 0 {
+                    return None;
+                }
+                let idx = mask.trailing_zeros();
+                mask &= mask - 1;
+                Some(((idx % width as u32) as u8, (idx / width as u32) as u8))
+            }))
+        } else {
+            let (range_x, range_y) = if height == 0 || width == 0 {
+                (0, 0)
+            } else {
+                (
+                    self.moves_range(width, DIR_X),
+                    self.moves_range(height, DIR_Y),
+                )
+            };
+            Either::Right(
+                (0..range_y)
+                    .flat_map(move |y| (0..range_x).map(move |x| (x, y)))
+                    .filter(move |&(x, y)| {
+                        self.grid.get(x, y) == Tile::Empty
+                            && self.grid.get((x + DIR_X) % width, (y + DIR_Y) % height)
+                                == Tile::Empty
+                    }),
+            )
+        }
+    }
+
+    /// Moves generated one by one, in the order of placements. Only placements that fill an edge
+    /// can lead to an already yielded position, so only their coordinates are remembered.
+    fn moves_iter_for<const DIR_X: u8, const DIR_Y: u8>(&self) -> impl Iterator<Item = Self> + '_
+    where
+        G: Clone + Eq,
+    {
+        let fills_edge = self.fills_edge_for::<DIR_X, DIR_Y>();
+        let mut filling = Vec::new();
+        self.placements_for::<DIR_X, DIR_Y>()
+            .filter_map(move |(x, y)| {
+                let position = self.place::<DIR_X, DIR_Y>(x, y);
+                if fills_edge((x, y)) {
+                    if filling.iter().any(|&(earlier_x, earlier_y)| {
+                        self.place::<DIR_X, DIR_Y>(earlier_x, earlier_y) == position
+                    }) {
+                        return None;
+                    }
+                    filling.push((x, y));
+                }
+                Some(position)
+            })
+    }
+
+    /// Number of places where the Left player can put a domino, counted without building new
+    /// positions. Placements that lead to the same position after
+    /// [`Domineering::move_top_left`] are counted separately, so it is an upper bound of
+    /// [`PartizanGame::num_left_moves`].
+    pub fn num_left_placements(&self) -> usize {
+        match self.convention {
+            Convention::LeftVertical => self.count_moves_for::<0, 1>(),
+            Convention::LeftHorizontal => self.count_moves_for::<1, 0>(),
+        }
+    }
+
+    /// Number of places where the Right player can put a domino, see
+    /// [`Domineering::num_left_placements`]
+    pub fn num_right_placements(&self) -> usize {
+        match self.convention {
+            Convention::LeftVertical => self.count_moves_for::<1, 0>(),
+            Convention::LeftHorizontal => self.count_moves_for::<0, 1>(),
+        }
+    }
+
+    /// Check if placement covers all empty tiles of the first or last non-empty row or column, see
+    /// [`Domineering::count_distinct_moves_for`]. Always false on a torus.
+    fn fills_edge_for<const DIR_X: u8, const DIR_Y: u8>(&self) -> impl Fn((u8, u8)) -> bool + '_ {
+        let width = self.grid.width();
+        let height = self.grid.height();
+        let row_empty = move |y| {
+            (0..width)
+                .filter(|&x| self.grid.get(x, y) == Tile::Empty)
+                .count()
+        };
+        let col_empty = move |x| {
+            (0..height)
+                .filter(|&y| self.grid.get(x, y) == Tile::Empty)
+                .count()
+        };
+
+        // (is row, index, number of empty tiles) of edges of the non-empty part of the board
+        let edges = (0..height)
+            .find(|&y| row_empty(y) > 0)
+            .filter(|_| self.topology == Topology::Flat)
+            .map(|top| {
+                let bottom = (0..height).rev().find(|&y| row_empty(y) > 0).unwrap_or(top);
+                let left = (0..width).find(|&x| col_empty(x) > 0).unwrap_or(0);
+                let right = (0..width).rev().find(|&x| col_empty(x) > 0).unwrap_or(left);
+                [
+                    (true, top, row_empty(top)),
+                    (true, bottom, row_empty(bottom)),
+                    (false, left, col_empty(left)),
+                    (false, right, col_empty(right)),
+                ]
+            });
+
+        move |(x, y)| {
+            let tiles = [(x, y), (x + DIR_X, y + DIR_Y)];
+            edges.iter().flatten().any(|&(is_row, idx, empty)| {
+                let covered = tiles
+                    .iter()
+                    .filter(|&&(tile_x, tile_y)| if is_row { tile_y == idx } else { tile_x == idx })
+                    .count();
+                covered == empty
+            })
+        }
+    }
+
+    /// Number of distinct positions reachable with dominoes in the given direction. A placement
+    /// that leaves an empty tile in every edge row and column keeps the board size after
+    /// [`Domineering::move_top_left`], so it leads to a position different from all others and is
+    /// only counted. Positions are built only for placements that fill an edge, as these may
+    /// collapse to the same position.
+    fn count_distinct_moves_for<const DIR_X: u8, const DIR_Y: u8>(&self) -> usize
+    where
+        G: Clone + Eq,
+    {
+        // Nothing is removed from a torus, so every placement leads to a different position
+        if self.topology == Topology::Torus {
+            return self.count_moves_for::<DIR_X, DIR_Y>();
+        }
+
+        let fills_edge = self.fills_edge_for::<DIR_X, DIR_Y>();
+
+        let mut res = 0;
+        for (idx, (x, y)) in self.placements_for::<DIR_X, DIR_Y>().enumerate() {
+            if !fills_edge((x, y)) {
+                res += 1;
+                continue;
+            }
+
+            let position = self.place::<DIR_X, DIR_Y>(x, y);
+            let is_new = self
+                .placements_for::<DIR_X, DIR_Y>()
+                .take(idx)
+                .filter(|&earlier| fills_edge(earlier))
+                .all(|(earlier_x, earlier_y)| {
+                    self.place::<DIR_X, DIR_Y>(earlier_x, earlier_y) != position
+                });
+            if is_new {
+                res += 1;
+            }
+        }
+        res
+    }
+
+    fn count_moves_for<const DIR_X: u8, const DIR_Y: u8>(&self) -> usize {
+        if let Some(mask) = self.placements_mask::<DIR_X, DIR_Y>() {
+            return mask.count_ones() as usize;
+        }
+
+        let width = self.grid.width();
+        let height = self.grid.height();
+        if height == 0 || width == 0 {
+            return 0;
+        }
+
+        let mut res = 0;
+        for y in 0..self.moves_range(height, DIR_Y) {
+            for x in 0..self.moves_range(width, DIR_X) {
+                if self.grid.get(x, y) == Tile::Empty
+                    && self.grid.get((x + DIR_X) % width, (y + DIR_Y) % height) == Tile::Empty
+                {
+                    res += 1;
+                }
+            }
+        }
+        res
+    }
+
+    /// Position after placing a domino with the first tile at `(x, y)`
+    fn place<const DIR_X: u8, const DIR_Y: u8>(&self, x: u8, y: u8) -> Self
+    where
+        G: Clone,
+    {
+        let next_x = (x + DIR_X) % self.grid.width();
+        let next_y = (y + DIR_Y) % self.grid.height();
+        let mut new_grid = self.clone();
+        new_grid.grid.set(x, y, Tile::Taken);
+        new_grid.grid.set(next_x, next_y, Tile::Taken);
+        new_grid.move_top_left()
+    }
+
+    fn moves_for<const DIR_X: u8, const DIR_Y: u8>(&self) -> MoveList<Self>
     where
         G: Ord + Clone,
     {
-        let mut moves = Vec::new();
+        let Some(mut mask) = self.placements_mask::<DIR_X, DIR_Y>() else {
+            return self.moves_for_by_tile::<DIR_X, DIR_Y>();
+        };
+
+        let width = self.grid.width() as u32;
+        let mut moves = MoveList::with_capacity(mask.count_ones() as usize);
+        while mask != 0 {
+            let idx = mask.trailing_zeros();
+            mask &= mask - 1;
+            moves.push(self.place::<DIR_X, DIR_Y>((idx % width) as u8, (idx / width) as u8));
+        }
+        moves.sort_unstable();
+        moves.dedup();
+        moves
+    }
+
+    /// Slower version of [`Domineering::moves_for`] that checks tiles one by one. Used for grids
+    /// that cannot be represented as a bit mask.
+    fn moves_for_by_tile<const DIR_X: u8, const DIR_Y: u8>(&self) -> MoveList<Self>
+    where
+        G: Ord + Clone,
+    {
+        let mut moves = MoveList::new();
 
-        if self.grid.height() == 0 || self.grid.width() == 0 {
+        let width = self.grid.width();
+        let height = self.grid.height();
+        if height == 0 || width == 0 {
             return moves;
         }
 
-        for y in 0..(self.grid.height() - DIR_Y) {
-            for x in 0..(self.grid.width() - DIR_X) {
-                let next_x = x + DIR_X;
-                let next_y = y + DIR_Y;
+        for y in 0..self.moves_range(height, DIR_Y) {
+            for x in 0..self.moves_range(width, DIR_X) {
+                let next_x = (x + DIR_X) % width;
+                let next_y = (y + DIR_Y) % height;
                 if self.grid.get(x, y) == Tile::Empty
                     && self.grid.get(next_x, next_y) == Tile::Empty
                 {
-                    let mut new_grid = self.clone();
-                    new_grid.grid.set(x, y, Tile::Taken);
-                    new_grid.grid.set(next_x, next_y, Tile::Taken);
-                    moves.push(new_grid.move_top_left());
+                    moves.push(self.place::<DIR_X, DIR_Y>(x, y));
                 }
             }
         }
@@ -162,7 +527,79 @@ where
         moves
     }
 
-    /// Remove filled rows and columns from the edges
+    /// Moves for the Left player, one per orbit of placements under flips that map the grid onto
+    /// itself, see [`FiniteGrid::tile_orbit`]. Useful for enumerating openings, where the empty
+    /// board has all the symmetries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cgt::short::partizan::games::domineering::Domineering;
+    /// use std::str::FromStr;
+    ///
+    /// // Dominoes at the left and right edge are mirror images of each other, filled edge
+    /// // columns are removed
+    /// let position: Domineering = Domineering::from_str("...|...").unwrap();
+    /// assert_eq!(
+    ///     position.distinct_opening_moves(),
+    ///     vec![
+    ///         Domineering::from_str("..|..").unwrap(),
+    ///         Domineering::from_str(".#.|.#.").unwrap(),
+    ///     ]
+    /// );
+    /// ```
+    pub fn distinct_opening_moves(&self) -> Vec<Self>
+    where
+        G: Clone,
+    {
+        match self.convention {
+            Convention::LeftVertical => self.distinct_moves_for::<0, 1>(),
+            Convention::LeftHorizontal => self.distinct_moves_for::<1, 0>(),
+        }
+    }
+
+    fn distinct_moves_for<const DIR_X: u8, const DIR_Y: u8>(&self) -> Vec<Self>
+    where
+        G: Clone,
+    {
+        let mut moves = Vec::new();
+
+        let width = self.grid.width();
+        let height = self.grid.height();
+        if height == 0 || width == 0 {
+            return moves;
+        }
+
+        let flips = self.grid.symmetry_flips();
+        // Order of tiles in a domino does not matter
+        let domino = |a: (u8, u8), b: (u8, u8)| if a <= b { (a, b) } else { (b, a) };
+
+        for y in 0..self.moves_range(height, DIR_Y) {
+            for x in 0..self.moves_range(width, DIR_X) {
+                let next_x = (x + DIR_X) % width;
+                let next_y = (y + DIR_Y) % height;
+                if self.grid.get(x, y) != Tile::Empty
+                    || self.grid.get(next_x, next_y) != Tile::Empty
+                {
+                    continue;
+                }
+
+                let placement = domino((x, y), (next_x, next_y));
+                let is_representative = self
+                    .grid
+                    .tile_orbit(&flips, x, y)
+                    .zip(self.grid.tile_orbit(&flips, next_x, next_y))
+                    .all(|(first, second)| placement <= domino(first, second));
+                if is_representative {
+                    moves.push(self.place::<DIR_X, DIR_Y>(x, y));
+                }
+            }
+        }
+        moves
+    }
+
+    /// Remove filled rows and columns from the edges. Positions on a torus are returned unchanged,
+    /// as removing rows or columns there would change adjacency of tiles.
     ///
     /// # Examples
     /// ```
@@ -175,8 +612,140 @@ where
     // Panic at `Self::empty(minimized_width, minimized_height).unwrap();` is unreachable
     #[must_use]
     #[cfg_attr(feature = "cargo-clippy", allow(clippy::missing_panics_doc))]
-    pub fn move_top_left(&self) -> Self {
-        Self::new(move_top_left(&self.grid, Tile::is_non_blocking))
+    pub fn move_top_left(&self) -> Self
+    where
+        G: Clone,
+    {
+        match self.topology {
+            Topology::Flat => self.with_grid(move_top_left(&self.grid, Tile::is_non_blocking)),
+            Topology::Torus => self.clone(),
+        }
+    }
+}
+
+impl Domineering {
+    /// Create a random position where each tile is filled independently with probability
+    /// `fill_probability`.
+    ///
+    /// # Errors
+    /// - Grid has more than 64 tiles
+    ///
+    /// # Panics
+    /// - `fill_probability` is not in range `[0, 1]`
+    pub fn random<R>(rng: &mut R, width: u8, height: u8, fill_probability: f64) -> Option<Self>
+    where
+        R: Rng + ?Sized,
+    {
+        let mut grid = SmallBitGrid::empty(width, height)?;
+        for y in 0..height {
+            for x in 0..width {
+                grid.set(x, y, Tile::bool_to_tile(rng.gen_bool(fill_probability)));
+            }
+        }
+        Some(Self::new(grid))
+    }
+
+    /// Stable representative of all positions that differ only by filled edges (see
+    /// [`Domineering::move_top_left`]) or by a symmetry preserving the value (see [`Symmetry`]).
+    ///
+    /// # Examples
+    /// ```
+    /// use cgt::short::partizan::games::domineering::Domineering;
+    /// use std::str::FromStr;
+    ///
+    /// let position: Domineering = Domineering::from_str("###|#..|#.#").unwrap();
+    /// let other: Domineering = Domineering::from_str(".#|..").unwrap();
+    /// assert_eq!(position.normalize(), other.normalize());
+    /// ```
+    #[must_use]
+    pub fn normalize(&self) -> Self {
+        let moved = self.move_top_left();
+        moved.symmetries().into_iter().min().unwrap_or(moved)
+    }
+
+    /// Compute canonical forms of all positions on a `width` by `height` grid, in order of their
+    /// grid ids (see [`SmallBitGrid::from_number`]). Positions are evaluated in parallel when
+    /// `rayon` feature is enabled.
+    ///
+    /// # Panics
+    /// - Grid has more than 63 tiles
+    pub fn all_canonical_forms<TT>(
+        width: u8,
+        height: u8,
+        transposition_table: &TT,
+    ) -> Vec<(Self, CanonicalForm)>
+    where
+        TT: TranspositionTable<Self> + Sync,
+    {
+        let tiles = width as u32 * height as u32;
+        assert!(tiles < u64::BITS, "grid cannot have more than 63 tiles");
+
+        #[cfg(feature = "rayon")]
+        let ids = (0..(1u64 << tiles)).into_par_iter();
+        #[cfg(not(feature = "rayon"))]
+        let ids = 0..(1u64 << tiles);
+
+        ids.map(|id| {
+            let position = Self::new(SmallBitGrid::from_number(width, height, id).unwrap());
+            let canonical_form = position.canonical_form(transposition_table);
+            (position, canonical_form)
+        })
+        .collect()
+    }
+
+    /// Find all positions on a `width` by `height` grid that are equal to `target`, in order of
+    /// their grid ids. Canonical forms are unique, so equal games have equal canonical forms.
+    ///
+    /// # Panics
+    /// - Grid has more than 63 tiles
+    pub fn positions_with_value<TT>(
+        width: u8,
+        height: u8,
+        target: &CanonicalForm,
+        transposition_table: &TT,
+    ) -> Vec<Self>
+    where
+        TT: TranspositionTable<Self> + Sync,
+    {
+        Self::all_canonical_forms(width, height, transposition_table)
+            .into_iter()
+            .filter_map(|(position, canonical_form)| {
+                (&canonical_form == target).then_some(position)
+            })
+            .collect()
+    }
+}
+
+impl GridEnumerable for Domineering {
+    /// All positions in order of their grid ids (see [`SmallBitGrid::from_number`])
+    ///
+    /// # Panics
+    /// - Grid has more than 63 tiles
+    fn positions_of_size(width: u8, height: u8) -> Vec<Self> {
+        let tiles = width as u32 * height as u32;
+        assert!(tiles < u64::BITS, "grid cannot have more than 63 tiles");
+        (0..(1u64 << tiles))
+            .map(|id| Self::new(SmallBitGrid::from_number(width, height, id).unwrap()))
+            .collect()
+    }
+}
+
+/// Only symmetries that keep vertical dominoes vertical preserve the value, i.e. flips along
+/// either axis and rotation by 180°. Rotation by 90° swaps the players and negates the value.
+impl Symmetry for Domineering {
+    fn symmetries(&self) -> Vec<Self> {
+        let vertical_flip = self.grid.vertical_flip();
+        vec![
+            *self,
+            self.with_grid(vertical_flip),
+            self.with_grid(self.grid.horizontal_flip()),
+            self.with_grid(vertical_flip.horizontal_flip()),
+        ]
+    }
+
+    /// Same as [`Domineering::normalize`], so translated positions also share the representative
+    fn canonical_symmetry(&self) -> Self {
+        self.normalize()
     }
 }
 
@@ -247,15 +816,18 @@ where
     ///
     /// let position: Domineering = Domineering::from_str("..#|.#.|##.").unwrap();
     /// assert_eq!(
-    ///     position.left_moves(),
-    ///     vec![
+    ///     position.left_moves().as_slice(),
+    ///     [
     ///         Domineering::from_str("..|.#").unwrap(),
     ///         Domineering::from_str(".#|#.|#.").unwrap(),
     ///     ]
     /// );
     /// ```
-    fn left_moves(&self) -> Vec<Self> {
-        self.moves_for::<0, 1>()
+    fn left_moves(&self) -> MoveList<Self> {
+        match self.convention {
+            Convention::LeftVertical => self.moves_for::<0, 1>(),
+            Convention::LeftHorizontal => self.moves_for::<1, 0>(),
+        }
     }
 
     /// Get moves for the Right player as positions he can move to.
@@ -272,12 +844,57 @@ where
     ///
     /// let position: Domineering = Domineering::from_str("..#|.#.|##.").unwrap();
     /// assert_eq!(
-    ///     position.right_moves(),
-    ///     vec![Domineering::from_str(".#.|##.").unwrap(),]
+    ///     position.right_moves().as_slice(),
+    ///     [Domineering::from_str(".#.|##.").unwrap()]
     /// );
     /// ```
-    fn right_moves(&self) -> Vec<Self> {
-        self.moves_for::<1, 0>()
+    fn right_moves(&self) -> MoveList<Self> {
+        match self.convention {
+            Convention::LeftVertical => self.moves_for::<1, 0>(),
+            Convention::LeftHorizontal => self.moves_for::<0, 1>(),
+        }
+    }
+
+    /// Count moves without collecting them, building positions only for placements that fill an
+    /// edge of the board.
+    fn num_left_moves(&self) -> usize {
+        match self.convention {
+            Convention::LeftVertical => self.count_distinct_moves_for::<0, 1>(),
+            Convention::LeftHorizontal => self.count_distinct_moves_for::<1, 0>(),
+        }
+    }
+
+    /// See [`Domineering::num_left_moves`]
+    fn num_right_moves(&self) -> usize {
+        match self.convention {
+            Convention::LeftVertical => self.count_distinct_moves_for::<1, 0>(),
+            Convention::LeftHorizontal => self.count_distinct_moves_for::<0, 1>(),
+        }
+    }
+
+    /// Place dominoes one by one as the iterator advances. Yields the same moves as
+    /// [`PartizanGame::left_moves`], but in the order of placements instead of sorted.
+    fn left_moves_iter(&self) -> impl Iterator<Item = Self> {
+        match self.convention {
+            Convention::LeftVertical => Either::Left(self.moves_iter_for::<0, 1>()),
+            Convention::LeftHorizontal => Either::Right(self.moves_iter_for::<1, 0>()),
+        }
+    }
+
+    /// See [`Domineering::left_moves_iter`]
+    fn right_moves_iter(&self) -> impl Iterator<Item = Self> {
+        match self.convention {
+            Convention::LeftVertical => Either::Left(self.moves_iter_for::<1, 0>()),
+            Convention::LeftHorizontal => Either::Right(self.moves_iter_for::<0, 1>()),
+        }
+    }
+
+    fn is_left_terminal(&self) -> bool {
+        self.num_left_placements() == 0
+    }
+
+    fn is_right_terminal(&self) -> bool {
+        self.num_right_placements() == 0
     }
 
     /// Get decompisitons of given position
@@ -301,10 +918,14 @@ where
     /// );
     /// ```
     fn decompositions(&self) -> Vec<Self> {
+        if self.topology == Topology::Torus {
+            return vec![self.clone()];
+        }
+
         let directions = [(1, 0), (-1, 0), (0, 1), (0, -1)];
         decompositions(&self.grid, Tile::is_non_blocking, Tile::Taken, &directions)
             .into_iter()
-            .map(Self::new)
+            .map(|grid| self.with_grid(grid))
             .collect::<Vec<_>>()
     }
 }
@@ -313,8 +934,14 @@ where
 mod tests {
     use super::*;
     use crate::{
-        numeric::dyadic_rational_number::DyadicRationalNumber,
-        short::partizan::transposition_table::ParallelTranspositionTable,
+        numeric::{dyadic_rational_number::DyadicRationalNumber, rational::Rational},
+        short::partizan::{
+            partizan_game::filter_by_temperature,
+            transposition_table::{
+                CacheStats, NoTranspositionTable, ParallelTranspositionTable,
+                SymmetricTranspositionTable,
+            },
+        },
     };
     use std::str::FromStr;
 
@@ -331,6 +958,28 @@ mod tests {
         assert_eq!(&format!("{}", pos), inp,);
     }
 
+    #[test]
+    fn parse_with_separator() {
+        let expected: Domineering = Domineering::from_str("..#|.#.|##.").unwrap();
+        for (input, separator) in [
+            ("..#\n.#.\n##.", '\n'),
+            ("..#\r\n.#.\r\n##.\r\n", '\n'),
+            ("..#;.#.;##.", ';'),
+            ("..#|.#.|##.", '|'),
+        ] {
+            assert_eq!(
+                Domineering::parse_with_separator(input, separator),
+                Ok(expected),
+                "{input:?}"
+            );
+        }
+
+        assert_eq!(
+            Domineering::<SmallBitGrid<Tile>>::parse_with_separator("..#\n.#", '\n'),
+            Err(GridParseError::NotRectangular)
+        );
+    }
+
     // Values confirmed with gcsuite
 
     #[cfg(test)]
@@ -400,6 +1049,484 @@ mod tests {
         dbg!(transposition_table.len());
         assert_eq!(&game_id.to_string(), "{1*|-1*}");
         assert_eq!(temp, DyadicRationalNumber::from(1));
+        assert_eq!(game_id.to_string_with_temperature(), "{1*|-1*} (t=1)");
+    }
+
+    #[test]
+    fn all_canonical_forms_match_serial() {
+        let transposition_table = ParallelTranspositionTable::new();
+        let all = Domineering::all_canonical_forms(3, 3, &transposition_table);
+        assert_eq!(all.len(), 1 << 9);
+
+        for (id, (position, canonical_form)) in all.into_iter().enumerate() {
+            let expected = Domineering::new(SmallBitGrid::from_number(3, 3, id as u64).unwrap());
+            assert_eq!(position, expected);
+            assert_eq!(
+                canonical_form,
+                expected.canonical_form(&NoTranspositionTable::new())
+            );
+        }
+    }
+
+    #[test]
+    fn positions_with_value_works() {
+        let transposition_table = ParallelTranspositionTable::new();
+        let zeros = Domineering::positions_with_value(
+            2,
+            2,
+            &CanonicalForm::new_integer(0),
+            &transposition_table,
+        );
+        assert_eq!(
+            zeros.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            vec![".#|#.", "##|#.", "#.|.#", "##|.#", "#.|##", ".#|##", "##|##"]
+        );
+    }
+
+    #[test]
+    fn game_tree_size_works() {
+        let position: Domineering = Domineering::from_str("..|..").unwrap();
+        assert_eq!(position.game_tree_size(100), Some(4));
+        assert_eq!(position.game_tree_size(3), None);
+    }
+
+    #[test]
+    fn num_moves_matches_moves() {
+        for position in [
+            "..#|.#.|##.",
+            "...|#..|...",
+            "",
+            ".#|#.",
+            "....|.#.#",
+            "..|..",
+            "...|...",
+        ] {
+            let position: Domineering = Domineering::from_str(position).unwrap();
+            assert_eq!(position.num_left_moves(), position.left_moves().len());
+            assert_eq!(position.num_right_moves(), position.right_moves().len());
+        }
+
+        for (width, height) in [(4, 4), (5, 3), (1, 6)] {
+            for id in 0..(1 << (width * height)) {
+                let position =
+                    Domineering::new(SmallBitGrid::from_number(width, height, id).unwrap());
+                for position in [
+                    position,
+                    position.with_convention(Convention::LeftHorizontal),
+                ] {
+                    assert_eq!(
+                        position.num_left_moves(),
+                        position.left_moves().len(),
+                        "{position}"
+                    );
+                    assert_eq!(
+                        position.num_right_moves(),
+                        position.right_moves().len(),
+                        "{position}"
+                    );
+                }
+            }
+        }
+
+        let torus: Domineering = Domineering::from_str("...|.#.|...")
+            .unwrap()
+            .with_topology(Topology::Torus);
+        assert_eq!(torus.num_left_moves(), torus.left_moves().len());
+        assert_eq!(torus.num_right_moves(), torus.right_moves().len());
+
+        // Symmetric placements are merged by `left_moves` but not by `num_left_placements`
+        let position: Domineering = Domineering::from_str("..|..").unwrap();
+        assert_eq!(position.num_left_moves(), 1);
+        assert_eq!(position.left_moves().len(), 1);
+        assert_eq!(position.num_left_placements(), 2);
+    }
+
+    #[test]
+    fn filter_by_temperature_works() {
+        let positions: Vec<Domineering> = ["..|..", "", ".."]
+            .into_iter()
+            .map(|position| Domineering::from_str(position).unwrap())
+            .collect();
+        let transposition_table = ParallelTranspositionTable::new();
+
+        let filtered: Vec<_> =
+            filter_by_temperature(positions.clone(), Rational::new(1, 2), &transposition_table)
+                .collect();
+        assert_eq!(filtered, vec![Domineering::from_str("..|..").unwrap()]);
+
+        let filtered: Vec<_> = filter_by_temperature(
+            positions.clone(),
+            Rational::NegativeInfinity,
+            &transposition_table,
+        )
+        .collect();
+        assert_eq!(filtered, positions);
+    }
+
+    #[test]
+    fn torus_has_more_moves() {
+        let flat: Domineering = Domineering::from_str("...|...|...").unwrap();
+        let torus = flat.with_topology(Topology::Torus);
+
+        assert_eq!(flat.num_left_moves(), 6);
+        assert_eq!(torus.num_left_moves(), 9);
+        assert_eq!(torus.num_right_moves(), 9);
+        assert_eq!(flat.left_moves().len(), 6);
+        assert_eq!(torus.left_moves().len(), 9);
+        assert!(torus
+            .left_moves()
+            .iter()
+            .all(|position| position.topology() == Topology::Torus));
+
+        // Wrapping domino takes the top and the bottom tile of the column
+        let torus: Domineering = Domineering::from_str(".##|###|.##")
+            .unwrap()
+            .with_topology(Topology::Torus);
+        assert_eq!(torus.num_left_moves(), 1);
+        assert_eq!(
+            torus.left_moves().as_slice(),
+            vec![Domineering::from_str("###|###|###")
+                .unwrap()
+                .with_topology(Topology::Torus)]
+        );
+
+        // 2 wide torus doesn't allow horizontal dominoes through the edge
+        let torus: Domineering = Domineering::from_str("..|..")
+            .unwrap()
+            .with_topology(Topology::Torus);
+        assert_eq!(torus.num_right_moves(), 2);
+    }
+
+    #[test]
+    fn flipped_convention_negates_value() {
+        for position in [
+            "..|..",
+            "...|...",
+            "..#|.#.|##.",
+            "...|#..|...",
+            ".#..|....|..#.",
+        ] {
+            let position: Domineering = Domineering::from_str(position).unwrap();
+            let flipped = position.with_convention(Convention::LeftHorizontal);
+            assert_eq!(
+                flipped.canonical_form(&NoTranspositionTable::new()),
+                -position.canonical_form(&NoTranspositionTable::new()),
+            );
+            assert_eq!(flipped.num_left_moves(), position.num_right_moves());
+            assert!(flipped
+                .left_moves()
+                .iter()
+                .chain(flipped.decompositions().iter())
+                .all(|position| position.convention() == Convention::LeftHorizontal));
+        }
+    }
+
+    #[test]
+    fn who_wins_works() {
+        use crate::short::partizan::outcome::{Outcome, Player};
+
+        let transposition_table = ParallelTranspositionTable::new();
+        let who_wins = |position| {
+            let position: Domineering = Domineering::from_str(position).unwrap();
+            position.who_wins(&transposition_table)
+        };
+
+        // * - first player wins
+        assert_eq!(
+            who_wins(".#|.."),
+            Outcome {
+                left_first: Player::Left,
+                right_first: Player::Right
+            }
+        );
+        // 1 - Left wins
+        assert_eq!(
+            who_wins(".|."),
+            Outcome {
+                left_first: Player::Left,
+                right_first: Player::Left
+            }
+        );
+    }
+
+    #[test]
+    fn left_moves_ordered_hottest_first() {
+        let transposition_table = ParallelTranspositionTable::new();
+
+        let position: Domineering = Domineering::from_str(".#..|....").unwrap();
+        assert_eq!(
+            position.canonical_form(&transposition_table).to_string(),
+            "{1*|-1*}"
+        );
+        let moves = position.left_moves_ordered(&transposition_table);
+        assert_eq!(moves.len(), 3);
+        assert_eq!(moves[0].0.to_string(), ".##.|..#.");
+        assert_eq!(moves[0].1.to_string(), "1*");
+
+        let position: Domineering = Domineering::from_str("...|...").unwrap();
+        let moves = position.left_moves_ordered(&transposition_table);
+        assert_eq!(
+            moves
+                .iter()
+                .map(|(_, value)| value.to_string())
+                .collect::<Vec<_>>(),
+            vec!["2", "{1|-1}"]
+        );
+    }
+
+    #[test]
+    fn canonical_form_approx_works() {
+        // On the empty 4x3 board moves with the highest mobility are already optimal
+        let position: Domineering = Domineering::from_str("....|....|...#").unwrap();
+        let exact = position.canonical_form(&NoTranspositionTable::new());
+        assert_eq!(
+            position.canonical_form_approx(&NoTranspositionTable::new(), 64),
+            exact
+        );
+        assert_ne!(
+            position.canonical_form_approx(&NoTranspositionTable::new(), 1),
+            exact
+        );
+
+        // Exact values from the table are preferred over approximations
+        let transposition_table = ParallelTranspositionTable::new();
+        position.canonical_form(&transposition_table);
+        assert_eq!(
+            position.canonical_form_approx(&transposition_table, 1),
+            exact
+        );
+    }
+
+    #[test]
+    fn terminal_positions() {
+        let position: Domineering = Domineering::from_str("##|##").unwrap();
+        assert!(position.is_terminal());
+
+        let position: Domineering = Domineering::from_str("").unwrap();
+        assert!(position.is_terminal());
+
+        let position: Domineering = Domineering::from_str(".#|.#").unwrap();
+        assert!(!position.is_left_terminal());
+        assert!(position.is_right_terminal());
+        assert!(!position.is_terminal());
+
+        for position in ["..|..", ".#.|#..", "...", "#|.|."] {
+            let position: Domineering = Domineering::from_str(position).unwrap();
+            assert_eq!(
+                position.is_left_terminal(),
+                position.left_moves().is_empty()
+            );
+            assert_eq!(
+                position.is_right_terminal(),
+                position.right_moves().is_empty()
+            );
+        }
+    }
+
+    #[test]
+    fn symmetric_transposition_table_merges_flips() {
+        let position: Domineering = Domineering::from_str("#...|....|..#.").unwrap();
+        let flipped = position.with_grid(position.grid().horizontal_flip());
+        assert_ne!(position, flipped);
+        assert_eq!(position.canonical_symmetry(), flipped.canonical_symmetry());
+
+        let transposition_table = SymmetricTranspositionTable::new();
+        let canonical_form = position.canonical_form(&transposition_table);
+        let cached = transposition_table.len();
+        assert_eq!(
+            transposition_table.lookup_position(&flipped),
+            Some(canonical_form.clone())
+        );
+        assert_eq!(flipped.canonical_form(&transposition_table), canonical_form);
+        assert_eq!(transposition_table.len(), cached);
+
+        // Rotation by 90° is not in the symmetry group of domineering
+        let rotated = position.with_grid(position.grid().rotate());
+        assert_eq!(transposition_table.lookup_position(&rotated), None);
+        assert_eq!(
+            rotated.canonical_form(&ParallelTranspositionTable::new()),
+            -canonical_form
+        );
+    }
+
+    #[test]
+    fn canonical_form_with_trace_works() {
+        let position: Domineering = Domineering::from_str("...|#.#|...").unwrap();
+        let transposition_table = ParallelTranspositionTable::new();
+        let (canonical_form, trace) = position.canonical_form_with_trace(&transposition_table);
+        assert_eq!(
+            canonical_form,
+            position.canonical_form(&NoTranspositionTable::new())
+        );
+        assert_eq!(trace.last(), Some(&(position, canonical_form)));
+
+        // Components of a decomposed position are traced separately
+        let component: Domineering = Domineering::from_str("...").unwrap();
+        assert!(trace
+            .iter()
+            .any(|(traced, value)| traced == &component && value.to_string() == "-1"));
+
+        for (traced, value) in trace {
+            assert_eq!(value, traced.canonical_form(&NoTranspositionTable::new()));
+        }
+    }
+
+    #[test]
+    fn lazy_moves_match_moves() {
+        for id in 0..(1 << 12) {
+            let position = Domineering::new(SmallBitGrid::from_number(4, 3, id).unwrap());
+            let mut left = position.left_moves_iter().collect::<Vec<_>>();
+            left.sort_unstable();
+            assert_eq!(left.as_slice(), position.left_moves().as_slice());
+
+            let mut right = position.right_moves_iter().collect::<Vec<_>>();
+            right.sort_unstable();
+            assert_eq!(right.as_slice(), position.right_moves().as_slice());
+        }
+
+        let torus: Domineering = Domineering::from_str("...|.#.|...")
+            .unwrap()
+            .with_topology(Topology::Torus);
+        let mut left = torus.left_moves_iter().collect::<Vec<_>>();
+        left.sort_unstable();
+        assert_eq!(left.as_slice(), torus.left_moves().as_slice());
+    }
+
+    #[test]
+    fn bitwise_moves_match_by_tile() {
+        for id in 0..(1 << 16) {
+            let position = Domineering::new(SmallBitGrid::from_number(4, 4, id).unwrap());
+            assert_eq!(position.left_moves(), position.moves_for_by_tile::<0, 1>());
+            assert_eq!(position.right_moves(), position.moves_for_by_tile::<1, 0>());
+        }
+
+        for position in ["...|..#", "....", ".|.|.", ""] {
+            let position: Domineering = Domineering::from_str(position).unwrap();
+            assert_eq!(position.left_moves(), position.moves_for_by_tile::<0, 1>());
+            assert_eq!(position.right_moves(), position.moves_for_by_tile::<1, 0>());
+        }
+
+        // Grid using all 64 bits
+        let position = Domineering::new(SmallBitGrid::empty(8, 8).unwrap());
+        assert_eq!(position.num_left_placements(), 56);
+        assert_eq!(position.num_right_placements(), 56);
+    }
+
+    #[test]
+    fn normalize_merges_translations_and_reflections() {
+        let position: Domineering = Domineering::from_str("..#|...").unwrap();
+        let normalized = position.normalize();
+        for equivalent in ["#..#|#...|####", "#..|...", "...|..#", "####|#...|##.."] {
+            let equivalent: Domineering = Domineering::from_str(equivalent).unwrap();
+            assert_eq!(equivalent.normalize(), normalized);
+        }
+
+        let rotated = position.with_grid(position.grid().rotate());
+        assert_ne!(rotated.normalize(), normalized);
+
+        let transposition_table = SymmetricTranspositionTable::new();
+        let canonical_form = position.canonical_form(&transposition_table);
+        let translated: Domineering = Domineering::from_str("####|##..|#...").unwrap();
+        assert_eq!(
+            transposition_table.lookup_position(&translated),
+            Some(canonical_form)
+        );
+    }
+
+    #[test]
+    fn moves_dedup_symmetry() {
+        let position = Domineering::new(SmallBitGrid::empty(3, 3).unwrap());
+        assert_eq!(position.left_moves().len(), 6);
+        // Domino in the middle column or in a side column
+        assert_eq!(position.left_moves_dedup_symmetry().len(), 2);
+        assert_eq!(position.right_moves_dedup_symmetry().len(), 2);
+
+        let transposition_table = ParallelTranspositionTable::new();
+        for m in position.left_moves() {
+            assert!(position
+                .left_moves_dedup_symmetry()
+                .iter()
+                .any(|d| d.canonical_form(&transposition_table)
+                    == m.canonical_form(&transposition_table)));
+        }
+    }
+
+    #[test]
+    fn regions_match_decompositions() {
+        let position: Domineering = Domineering::from_str("..#|.#.|##.").unwrap();
+        let regions = position.grid().regions(Tile::is_non_blocking);
+        assert_eq!(
+            regions,
+            vec![vec![(0, 0), (1, 0), (0, 1)], vec![(2, 1), (2, 2)]]
+        );
+        assert_eq!(regions.len(), position.decompositions().len());
+    }
+
+    #[test]
+    fn repeated_lookup_counts_hits() {
+        let position: Domineering = Domineering::from_str("...|.#.|...").unwrap();
+        let transposition_table = ParallelTranspositionTable::new();
+        position.canonical_form(&transposition_table);
+
+        let stats = transposition_table.stats();
+        assert!(stats.misses > 0);
+        assert_eq!(stats.known_grids, transposition_table.len());
+        assert!(stats.known_games <= stats.known_grids);
+
+        position.canonical_form(&transposition_table);
+        let repeated = transposition_table.stats();
+        assert_eq!(repeated.hits, stats.hits + 1);
+        assert_eq!(repeated.misses, stats.misses);
+
+        assert_eq!(
+            NoTranspositionTable::<Domineering>::new().stats(),
+            CacheStats::default()
+        );
+    }
+
+    #[test]
+    fn clearing_grids_keeps_values_correct() {
+        let position: Domineering = Domineering::from_str("....|.#..|....").unwrap();
+        let expected = position.canonical_form(&NoTranspositionTable::new());
+
+        let transposition_table = ParallelTranspositionTable::new();
+        assert_eq!(position.canonical_form(&transposition_table), expected);
+        let known_games = transposition_table.stats().known_games;
+        transposition_table.clear_grids();
+        assert!(transposition_table.is_empty());
+        assert_eq!(transposition_table.stats().known_games, known_games);
+        assert_eq!(position.canonical_form(&transposition_table), expected);
+
+        let transposition_table = ParallelTranspositionTable::with_max_positions(8);
+        assert_eq!(position.canonical_form(&transposition_table), expected);
+        assert!(transposition_table.len() <= 8);
+        assert!(!ParallelTranspositionTable::<Domineering>::new().is_full());
+    }
+
+    #[test]
+    fn random_is_deterministic() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let position = Domineering::random(&mut StdRng::seed_from_u64(42), 8, 8, 0.25).unwrap();
+        let same = Domineering::random(&mut StdRng::seed_from_u64(42), 8, 8, 0.25).unwrap();
+        assert_eq!(position, same);
+
+        // 16 filled tiles expected, the bound is very loose
+        let filled = 64 - position.free_places();
+        assert!((4..=32).contains(&filled), "{filled} tiles filled");
+
+        let mut rng = StdRng::seed_from_u64(0);
+        assert_eq!(
+            Domineering::random(&mut rng, 3, 3, 0.0).unwrap(),
+            Domineering::new(SmallBitGrid::empty(3, 3).unwrap())
+        );
+        assert_eq!(
+            Domineering::random(&mut rng, 3, 3, 1.0)
+                .unwrap()
+                .free_places(),
+            0
+        );
+        assert_eq!(Domineering::random(&mut rng, 9, 8, 0.5), None);
     }
 
     #[test]
@@ -412,6 +1539,63 @@ mod tests {
         );
     }
 
+    #[test]
+    fn value_matches_canonical_form() {
+        for position in ["..|..", "..#|.#.|##.", "...|...|...", "#...|....|..#."] {
+            let position: Domineering = Domineering::from_str(position).unwrap();
+            assert_eq!(
+                position.value(),
+                position.canonical_form(&ParallelTranspositionTable::new()),
+                "{position}"
+            );
+        }
+    }
+
+    #[test]
+    fn latex_with_scales_works() {
+        let position: Domineering = Domineering::from_str("#.|..|..").unwrap();
+        assert_eq!(
+            &position.to_latex_with_scales(0.5, 0.25),
+            r#"\begin{tikzpicture}[xscale=0.5,yscale=0.25] \fill[fill=gray] (0,0) rectangle (1,1); \draw[step=1cm,black] (0,0) grid (2, 3); \end{tikzpicture}"#
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Scale must be positive")]
+    fn latex_zero_scale_is_rejected() {
+        let position: Domineering = Domineering::from_str("..|..").unwrap();
+        let _ = position.to_latex_with_scale(0.);
+    }
+
+    #[test]
+    fn distinct_opening_moves_on_empty_board() {
+        let position: Domineering = Domineering::from_str("....|....|....|....").unwrap();
+        assert_eq!(position.left_moves().len(), 12);
+
+        // Columns {0, 3} and {1, 2}, rows {0-1, 2-3} and {1-2}
+        let openings = position.distinct_opening_moves();
+        assert_eq!(openings.len(), 4);
+        assert!(openings
+            .iter()
+            .all(|opening| position.left_moves().contains(opening)));
+
+        let asymmetric: Domineering = Domineering::from_str("#...|....|....|....").unwrap();
+        assert_eq!(
+            asymmetric.distinct_opening_moves().len(),
+            asymmetric.num_left_moves()
+        );
+    }
+
+    #[test]
+    fn latex_row_works() {
+        let position: Domineering = Domineering::from_str("#.|..").unwrap();
+        let temperature = Rational::from_str("3/4").unwrap();
+        assert_eq!(
+            position.to_latex_row(&temperature, 0.5),
+            r#"\begin{tikzpicture}[scale=0.5] \fill[fill=gray] (0,0) rectangle (1,1); \draw[step=1cm,black] (0,0) grid (2, 2); \end{tikzpicture} & $3/4$ "#
+        );
+    }
+
     /// Assert temperature value without going through canonical form
     /// Using macro yields better error location on assertion failure
     #[cfg(test)]
@@ -429,7 +1613,18 @@ mod tests {
         assert_temperature!(Domineering::from_str(""), -1);
         assert_temperature!(Domineering::from_str(".."), -1);
         assert_temperature!(Domineering::from_str("..|.#"), 0);
-        // FIXME: takes too long
-        // assert_temperature!(Domineering::from_str("#...|....|....|...."), 1);
+        assert_temperature!(Domineering::from_str("#...|....|....|...."), 1);
+    }
+
+    #[test]
+    fn temperature_direct_matches_canonical_form() {
+        let transposition_table = ParallelTranspositionTable::new();
+        for position in ["..|.#", "#...|....|....|....", "..#|...|#.."] {
+            let position: Domineering = Domineering::from_str(position).unwrap();
+            assert_eq!(
+                position.temperature_direct(),
+                position.canonical_form(&transposition_table).temperature()
+            );
+        }
     }
 }
@@ -2,8 +2,8 @@
 
 use crate::{
     drawing::svg::{self, ImmSvg, Svg},
-    grid::{vec_grid::VecGrid, FiniteGrid, Grid},
-    short::partizan::partizan_game::PartizanGame,
+    grid::{vec_grid::VecGrid, FiniteGrid, Grid, GridParseError},
+    short::partizan::partizan_game::{MoveList, PartizanGame},
 };
 use cgt_derive::Tile;
 use std::{
@@ -49,10 +49,10 @@ impl<G> FromStr for Fission<G>
 where
     G: Grid<Item = Tile> + FiniteGrid,
 {
-    type Err = ();
+    type Err = GridParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self::new(G::parse(s).ok_or(())?))
+        Ok(Self::new(G::parse(s)?))
     }
 }
 
@@ -67,11 +67,11 @@ where
     }
 
     #[inline]
-    fn moves_for<const DIR_X: u8, const DIR_Y: u8>(&self) -> Vec<Self>
+    fn moves_for<const DIR_X: u8, const DIR_Y: u8>(&self) -> MoveList<Self>
     where
         G: Clone,
     {
-        let mut moves = Vec::new();
+        let mut moves = MoveList::new();
 
         if self.grid.height() == 0 || self.grid.width() == 0 {
             return moves;
@@ -105,11 +105,11 @@ impl<G> PartizanGame for Fission<G>
 where
     G: Grid<Item = Tile> + FiniteGrid + Clone + Hash + Send + Sync + Eq,
 {
-    fn left_moves(&self) -> Vec<Self> {
+    fn left_moves(&self) -> MoveList<Self> {
         self.moves_for::<0, 1>()
     }
 
-    fn right_moves(&self) -> Vec<Self> {
+    fn right_moves(&self) -> MoveList<Self> {
         self.moves_for::<1, 0>()
     }
 }
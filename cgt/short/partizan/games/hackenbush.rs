@@ -0,0 +1,242 @@
+//! Hackenbush is played on a graph with colored edges, where some vertices are connected to the
+//! ground. Left cuts blue or green edges, Right cuts red or green edges. After each cut, edges
+//! that are no longer connected to the ground are removed.
+
+use crate::{
+    graph::undirected::Graph,
+    short::partizan::partizan_game::{MoveList, PartizanGame},
+};
+
+/// Color of a Hackenbush edge
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EdgeColor {
+    /// Edge that only Left can cut
+    Blue,
+
+    /// Edge that only Right can cut
+    Red,
+
+    /// Edge that both players can cut
+    Green,
+}
+
+/// Edge between two vertices. Parallel edges and loops are allowed.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Edge {
+    /// First endpoint
+    pub from: usize,
+
+    /// Second endpoint
+    pub to: usize,
+
+    /// Color of the edge
+    pub color: EdgeColor,
+}
+
+impl Edge {
+    /// Create new edge
+    pub const fn new(from: usize, to: usize, color: EdgeColor) -> Self {
+        Self { from, to, color }
+    }
+}
+
+/// Position of a [hackenbush](self) game
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hackenbush {
+    grounded: Vec<bool>,
+    edges: Vec<Edge>,
+}
+
+impl Hackenbush {
+    /// Create new position where `grounded[v]` marks vertex `v` as connected to the ground.
+    /// Edges not connected to the ground are removed.
+    ///
+    /// Returns [None] if any edge has an endpoint outside of `grounded`.
+    pub fn new(grounded: Vec<bool>, edges: Vec<Edge>) -> Option<Self> {
+        if edges
+            .iter()
+            .any(|edge| edge.from >= grounded.len() || edge.to >= grounded.len())
+        {
+            return None;
+        }
+
+        let mut position = Self { grounded, edges };
+        position.normalize();
+        Some(position)
+    }
+
+    /// Get ground markers of vertices
+    pub fn grounded(&self) -> &[bool] {
+        &self.grounded
+    }
+
+    /// Get edges that are still in the game
+    pub fn edges(&self) -> &[Edge] {
+        &self.edges
+    }
+
+    /// Remove edges that are not connected to the ground and sort the rest
+    fn normalize(&mut self) {
+        let graph = Graph::from_edges(
+            self.grounded.len(),
+            &self
+                .edges
+                .iter()
+                .map(|edge| (edge.from, edge.to))
+                .collect::<Vec<_>>(),
+        );
+
+        let mut connected_to_ground = vec![false; self.grounded.len()];
+        for component in graph.connected_components() {
+            if component.iter().any(|v| self.grounded[*v]) {
+                for v in component {
+                    connected_to_ground[v] = true;
+                }
+            }
+        }
+
+        self.edges.retain(|edge| connected_to_ground[edge.from]);
+        for edge in &mut self.edges {
+            if edge.from > edge.to {
+                std::mem::swap(&mut edge.from, &mut edge.to);
+            }
+        }
+        self.edges.sort_unstable();
+    }
+
+    fn moves_for(&self, own_color: EdgeColor) -> MoveList<Self> {
+        let mut moves = MoveList::with_capacity(self.edges.len());
+        for (idx, edge) in self.edges.iter().enumerate() {
+            if edge.color == own_color || edge.color == EdgeColor::Green {
+                let mut position = self.clone();
+                position.edges.remove(idx);
+                position.normalize();
+                moves.push(position);
+            }
+        }
+        moves.sort_unstable_by(|lhs, rhs| lhs.edges.cmp(&rhs.edges));
+        moves.dedup();
+        moves
+    }
+}
+
+impl PartizanGame for Hackenbush {
+    fn left_moves(&self) -> MoveList<Self> {
+        self.moves_for(EdgeColor::Blue)
+    }
+
+    fn right_moves(&self) -> MoveList<Self> {
+        self.moves_for(EdgeColor::Red)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::short::partizan::transposition_table::ParallelTranspositionTable;
+
+    macro_rules! assert_value {
+        ($grounded:expr, $edges:expr, $value:expr) => {
+            let position = Hackenbush::new($grounded, $edges).unwrap();
+            let transposition_table = ParallelTranspositionTable::new();
+            assert_eq!(
+                position.canonical_form(&transposition_table).to_string(),
+                $value
+            );
+        };
+    }
+
+    #[test]
+    fn single_edges() {
+        let grounded = vec![true, false];
+        assert_value!(
+            grounded.clone(),
+            vec![Edge::new(0, 1, EdgeColor::Blue)],
+            "1"
+        );
+        assert_value!(
+            grounded.clone(),
+            vec![Edge::new(0, 1, EdgeColor::Red)],
+            "-1"
+        );
+        assert_value!(grounded, vec![Edge::new(0, 1, EdgeColor::Green)], "*");
+    }
+
+    #[test]
+    fn stalks() {
+        let grounded = vec![true, false, false];
+        assert_value!(
+            grounded.clone(),
+            vec![
+                Edge::new(0, 1, EdgeColor::Blue),
+                Edge::new(1, 2, EdgeColor::Red)
+            ],
+            "1/2"
+        );
+        assert_value!(
+            grounded.clone(),
+            vec![
+                Edge::new(0, 1, EdgeColor::Green),
+                Edge::new(1, 2, EdgeColor::Green)
+            ],
+            "*2"
+        );
+        assert_value!(
+            grounded,
+            vec![
+                Edge::new(0, 1, EdgeColor::Blue),
+                Edge::new(1, 2, EdgeColor::Green)
+            ],
+            "1*"
+        );
+    }
+
+    #[test]
+    fn cut_removes_floating_edges() {
+        let position = Hackenbush::new(
+            vec![true, false, false, false],
+            vec![
+                Edge::new(0, 1, EdgeColor::Red),
+                Edge::new(1, 2, EdgeColor::Blue),
+                Edge::new(2, 3, EdgeColor::Blue),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            position.right_moves().as_slice(),
+            vec![Hackenbush::new(vec![true, false, false, false], vec![]).unwrap()]
+        );
+
+        // Edge that is not grounded is not part of the game
+        let position = Hackenbush::new(
+            vec![true, false, false],
+            vec![Edge::new(1, 2, EdgeColor::Blue)],
+        )
+        .unwrap();
+        assert!(position.edges().is_empty());
+
+        assert_eq!(
+            Hackenbush::new(vec![true], vec![Edge::new(0, 1, EdgeColor::Blue)]),
+            None
+        );
+    }
+
+    #[test]
+    fn cycles_and_parallel_edges() {
+        // Blue and red edges between ground and the same vertex cancel out
+        assert_value!(
+            vec![true, false],
+            vec![
+                Edge::new(0, 1, EdgeColor::Blue),
+                Edge::new(0, 1, EdgeColor::Red)
+            ],
+            "0"
+        );
+
+        // Green loop at the ground is the same as a single green edge
+        assert_value!(vec![true], vec![Edge::new(0, 0, EdgeColor::Green)], "*");
+    }
+}
@@ -11,8 +11,12 @@
 
 use crate::{
     drawing::svg::{self, ImmSvg, Svg},
-    grid::{vec_grid::VecGrid, CharTile, FiniteGrid, Grid},
-    short::partizan::{canonical_form::CanonicalForm, partizan_game::PartizanGame},
+    grid::{vec_grid::VecGrid, CharTile, FiniteGrid, Grid, GridParseError},
+    short::partizan::{
+        canonical_form::CanonicalForm,
+        partizan_game::{MoveList, PartizanGame},
+        transposition_table::TranspositionTable,
+    },
 };
 use core::fmt;
 use std::{fmt::Display, hash::Hash, str::FromStr};
@@ -70,6 +74,31 @@ impl CharTile for Tile {
     }
 }
 
+/// Type of skier move
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SkiMoveKind {
+    /// Skier moved horizontally, possibly off the board
+    Slide,
+
+    /// Skier jumped down over a skier of the opposite color
+    Jump,
+}
+
+/// Description of a single skier move
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SkiMove {
+    /// Position of the skier before the move
+    pub from: (u8, u8),
+
+    /// Position of the skier after the move, [None] if skier moved off the board
+    pub to: Option<(u8, u8)>,
+
+    /// Type of the move
+    pub kind: SkiMoveKind,
+}
+
 // NOTE: Consider caching positions of left and right skiers to avoid quadratic loops
 /// Ski Jumps game
 #[derive(Debug, Hash, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -91,10 +120,10 @@ impl<G> FromStr for SkiJumps<G>
 where
     G: Grid<Item = Tile> + FiniteGrid,
 {
-    type Err = ();
+    type Err = GridParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self::new(G::parse(s).ok_or(())?))
+        Ok(Self::new(G::parse(s)?))
     }
 }
 
@@ -108,85 +137,11 @@ where
         Self { grid }
     }
 
-    /// Check if jumping move is possible
-    pub fn jump_available(&self) -> bool {
-        for y in 0..self.grid.height() {
-            for x in 0..self.grid.width() {
-                // Check if in a row below current row, there is a tile that can be jumped over
-                let current = self.grid.get(x, y);
-                for dx in 0..self.grid.width() {
-                    if y + 1 < self.grid.height() {
-                        match (current, self.grid.get(dx, y + 1)) {
-                            (Tile::Left(Skier::Jumper), Tile::Right(_))
-                            | (Tile::Right(Skier::Jumper), Tile::Left(_)) => {
-                                return true;
-                            }
-                            _ => {}
-                        }
-                    }
-                }
-            }
-        }
-
-        false
-    }
-}
-
-#[cfg(not(tarpaulin_include))]
-impl<G> Svg for SkiJumps<G>
-where
-    G: Grid<Item = Tile> + FiniteGrid,
-{
-    fn to_svg<W>(&self, buf: &mut W) -> fmt::Result
+    /// Get moves for the Left player together with description of the move that was made
+    pub fn left_moves_annotated(&self) -> Vec<(SkiMove, Self)>
     where
-        W: fmt::Write,
+        G: Clone,
     {
-        // Chosen arbitrarily
-        let tile_size = 48;
-        let grid_width = 4;
-
-        let offset = grid_width / 2;
-        let svg_width = self.grid.width() as u32 * tile_size + grid_width;
-        let svg_height = self.grid.height() as u32 * tile_size + grid_width;
-
-        ImmSvg::new(buf, svg_width, svg_height, |buf| {
-            for y in 0..self.grid.height() {
-                for x in 0..self.grid.width() {
-                    match self.grid.get(x, y) {
-                        Tile::Empty => {}
-                        tile => {
-                            let text = svg::Text {
-                                x: (x as u32 * tile_size + offset + tile_size / 2) as i32,
-                                y: (y as u32 * tile_size + offset + (0.6 * tile_size as f32) as u32)
-                                    as i32,
-                                text: tile.tile_to_char().to_string(),
-                                text_anchor: svg::TextAnchor::Middle,
-                                ..svg::Text::default()
-                            };
-                            ImmSvg::text(buf, &text)?;
-                        }
-                    }
-                }
-            }
-
-            let grid = svg::Grid {
-                x1: 0,
-                y1: 0,
-                x2: svg_width as i32,
-                y2: svg_height as i32,
-                grid_width,
-                tile_size,
-            };
-            ImmSvg::grid(buf, &grid)
-        })
-    }
-}
-
-impl<G> PartizanGame for SkiJumps<G>
-where
-    G: Grid<Item = Tile> + FiniteGrid + Clone + Hash + Send + Sync + Eq,
-{
-    fn left_moves(&self) -> Vec<Self> {
         let mut moves = vec![];
 
         for y in 0..self.grid.height() {
@@ -199,12 +154,26 @@ where
                             if dx == self.grid.width() {
                                 let mut new_grid = self.grid.clone();
                                 new_grid.set(x, y, Tile::Empty);
-                                moves.push(Self::new(new_grid));
+                                moves.push((
+                                    SkiMove {
+                                        from: (x, y),
+                                        to: None,
+                                        kind: SkiMoveKind::Slide,
+                                    },
+                                    Self::new(new_grid),
+                                ));
                             } else if self.grid.get(dx, y) == Tile::Empty {
                                 let mut new_grid = self.grid.clone();
                                 new_grid.set(x, y, Tile::Empty);
                                 new_grid.set(dx, y, tile_to_move);
-                                moves.push(Self::new(new_grid));
+                                moves.push((
+                                    SkiMove {
+                                        from: (x, y),
+                                        to: Some((dx, y)),
+                                        kind: SkiMoveKind::Slide,
+                                    },
+                                    Self::new(new_grid),
+                                ));
                             } else {
                                 // Blocked, cannot go any further
                                 break;
@@ -212,7 +181,13 @@ where
                         }
 
                         // Check jump
-                        if skier == Skier::Jumper && y + 1 < self.grid.height() {
+                        // Jumper lands two rows below, so the landing tile must be empty or
+                        // off the board
+                        if skier == Skier::Jumper
+                            && y + 1 < self.grid.height()
+                            && (y + 2 >= self.grid.height()
+                                || self.grid.get(x, y + 2) == Tile::Empty)
+                        {
                             match self.grid.get(x, y + 1) {
                                 Tile::Empty | Tile::Left(_) => {}
                                 Tile::Right(_) => {
@@ -222,7 +197,14 @@ where
                                     if y + 2 < self.grid.height() {
                                         new_grid.set(x, y + 2, Tile::Left(Skier::Jumper));
                                     }
-                                    moves.push(Self::new(new_grid));
+                                    moves.push((
+                                        SkiMove {
+                                            from: (x, y),
+                                            to: (y + 2 < self.grid.height()).then_some((x, y + 2)),
+                                            kind: SkiMoveKind::Jump,
+                                        },
+                                        Self::new(new_grid),
+                                    ));
                                 }
                             }
                         }
@@ -234,7 +216,11 @@ where
         moves
     }
 
-    fn right_moves(&self) -> Vec<Self> {
+    /// Get moves for the Right player together with description of the move that was made
+    pub fn right_moves_annotated(&self) -> Vec<(SkiMove, Self)>
+    where
+        G: Clone,
+    {
         let mut moves = vec![];
 
         for y in 0..self.grid.height() {
@@ -250,12 +236,26 @@ where
                             if dx == 0 {
                                 let mut new_grid = self.grid.clone();
                                 new_grid.set(x, y, Tile::Empty);
-                                moves.push(Self::new(new_grid));
+                                moves.push((
+                                    SkiMove {
+                                        from: (x, y),
+                                        to: None,
+                                        kind: SkiMoveKind::Slide,
+                                    },
+                                    Self::new(new_grid),
+                                ));
                             } else if self.grid.get(dx - 1, y) == Tile::Empty {
                                 let mut new_grid = self.grid.clone();
                                 new_grid.set(x, y, Tile::Empty);
                                 new_grid.set(dx - 1, y, tile_to_move);
-                                moves.push(Self::new(new_grid));
+                                moves.push((
+                                    SkiMove {
+                                        from: (x, y),
+                                        to: Some((dx - 1, y)),
+                                        kind: SkiMoveKind::Slide,
+                                    },
+                                    Self::new(new_grid),
+                                ));
                             } else {
                                 // Blocked, cannot go any further
                                 break;
@@ -263,7 +263,13 @@ where
                         }
 
                         // Check jump
-                        if skier == Skier::Jumper && y + 1 < self.grid.height() {
+                        // Jumper lands two rows below, so the landing tile must be empty or
+                        // off the board
+                        if skier == Skier::Jumper
+                            && y + 1 < self.grid.height()
+                            && (y + 2 >= self.grid.height()
+                                || self.grid.get(x, y + 2) == Tile::Empty)
+                        {
                             match self.grid.get(x, y + 1) {
                                 Tile::Empty | Tile::Right(_) => {}
                                 Tile::Left(_) => {
@@ -273,7 +279,14 @@ where
                                     if y + 2 < self.grid.height() {
                                         new_grid.set(x, y + 2, Tile::Right(Skier::Jumper));
                                     }
-                                    moves.push(Self::new(new_grid));
+                                    moves.push((
+                                        SkiMove {
+                                            from: (x, y),
+                                            to: (y + 2 < self.grid.height()).then_some((x, y + 2)),
+                                            kind: SkiMoveKind::Jump,
+                                        },
+                                        Self::new(new_grid),
+                                    ));
                                 }
                             }
                         }
@@ -285,10 +298,123 @@ where
         moves
     }
 
+    /// Check if there is a row where Left skier is to the left of Right skier, so they may block
+    /// each other
+    fn blocking_available(&self) -> bool {
+        (0..self.grid.height()).any(|y| {
+            let mut left_seen = false;
+            (0..self.grid.width()).any(|x| match self.grid.get(x, y) {
+                Tile::Left(_) => {
+                    left_seen = true;
+                    false
+                }
+                Tile::Right(_) => left_seen,
+                Tile::Empty => false,
+            })
+        })
+    }
+
+    /// Check if jumping move is possible
+    pub fn jump_available(&self) -> bool {
+        for y in 0..self.grid.height() {
+            for x in 0..self.grid.width() {
+                // Check if in a row below current row, there is a tile that can be jumped over
+                let current = self.grid.get(x, y);
+                for dx in 0..self.grid.width() {
+                    if y + 1 < self.grid.height() {
+                        match (current, self.grid.get(dx, y + 1)) {
+                            (Tile::Left(Skier::Jumper), Tile::Right(_))
+                            | (Tile::Right(Skier::Jumper), Tile::Left(_)) => {
+                                return true;
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+            }
+        }
+
+        false
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<G> Svg for SkiJumps<G>
+where
+    G: Grid<Item = Tile> + FiniteGrid,
+{
+    fn to_svg<W>(&self, buf: &mut W) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        // Chosen arbitrarily
+        let tile_size = 48;
+        let grid_width = 4;
+
+        let offset = grid_width / 2;
+        let svg_width = self.grid.width() as u32 * tile_size + grid_width;
+        let svg_height = self.grid.height() as u32 * tile_size + grid_width;
+        let viewport = svg::Viewport {
+            scale: tile_size as f32,
+            origin: (offset as i32, offset as i32),
+        };
+
+        ImmSvg::new(buf, svg_width, svg_height, |buf| {
+            for y in 0..self.grid.height() {
+                for x in 0..self.grid.width() {
+                    match self.grid.get(x, y) {
+                        Tile::Empty => {}
+                        tile => {
+                            let (text_x, text_y) = viewport.map(x as f32 + 0.5, y as f32 + 0.6);
+                            let text = svg::Text {
+                                x: text_x,
+                                y: text_y,
+                                text: tile.tile_to_char().to_string(),
+                                text_anchor: svg::TextAnchor::Middle,
+                                ..svg::Text::default()
+                            };
+                            ImmSvg::text(buf, &text)?;
+                        }
+                    }
+                }
+            }
+
+            let grid = svg::Grid {
+                x1: 0,
+                y1: 0,
+                x2: svg_width as i32,
+                y2: svg_height as i32,
+                grid_width,
+                tile_size,
+            };
+            ImmSvg::grid(buf, &grid)
+        })
+    }
+}
+
+impl<G> PartizanGame for SkiJumps<G>
+where
+    G: Grid<Item = Tile> + FiniteGrid + Clone + Hash + Send + Sync + Eq,
+{
+    fn left_moves(&self) -> MoveList<Self> {
+        self.left_moves_annotated()
+            .into_iter()
+            .map(|(_, position)| position)
+            .collect()
+    }
+
+    fn right_moves(&self) -> MoveList<Self> {
+        self.right_moves_annotated()
+            .into_iter()
+            .map(|(_, position)| position)
+            .collect()
+    }
+
     fn reductions(&self) -> Option<CanonicalForm> {
-        // If neither player can jump, the optimal move is to move any of the pieces by one tile
-        // so the game value is the difference of sum of distances to the board edge
-        if !self.jump_available() {
+        // If neither player can jump and no skiers can block the opponent, the optimal move is to
+        // move any of the pieces by one tile so the game value is the difference of sum of
+        // distances to the board edge
+        if !self.jump_available() && !self.blocking_available() {
             let mut value = 0i64;
             for y in 0..self.grid.height() {
                 for x in 0..self.grid.width() {
@@ -304,6 +430,31 @@ where
 
         None
     }
+
+    /// Positions equal to their horizontal mirror image with skier colors swapped are symmetric
+    /// without computing the canonical form
+    fn is_symmetric<TT>(&self, transposition_table: &TT) -> bool
+    where
+        TT: TranspositionTable<Self> + Sync,
+    {
+        let width = self.grid.width();
+        let mirrored = (0..self.grid.height()).all(|y| {
+            (0..width).all(|x| {
+                let swapped = match self.grid.get(width - 1 - x, y) {
+                    Tile::Empty => Tile::Empty,
+                    Tile::Left(skier) => Tile::Right(skier),
+                    Tile::Right(skier) => Tile::Left(skier),
+                };
+                self.grid.get(x, y) == swapped
+            })
+        });
+        if mirrored {
+            return true;
+        }
+
+        let value = self.canonical_form(transposition_table);
+        value == -&value
+    }
 }
 
 #[cfg(test)]
@@ -321,6 +472,49 @@ mod tests {
         }};
     }
 
+    #[test]
+    fn jump_is_annotated() {
+        let position: SkiJumps = SkiJumps::from_str("L...|R...|....").unwrap();
+        let jumps = position
+            .left_moves_annotated()
+            .into_iter()
+            .filter(|(ski_move, _)| ski_move.kind == SkiMoveKind::Jump)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            jumps,
+            vec![(
+                SkiMove {
+                    from: (0, 0),
+                    to: Some((0, 2)),
+                    kind: SkiMoveKind::Jump,
+                },
+                SkiJumps::from_str("....|r...|L...").unwrap()
+            )]
+        );
+    }
+
+    #[test]
+    fn jump_requires_empty_landing_tile() {
+        let position: SkiJumps = SkiJumps::from_str("L.|R.|r.").unwrap();
+        assert!(position
+            .left_moves_annotated()
+            .iter()
+            .all(|(ski_move, _)| ski_move.kind != SkiMoveKind::Jump));
+
+        let position: SkiJumps = SkiJumps::from_str(".R|.L|.l").unwrap();
+        assert!(position
+            .right_moves_annotated()
+            .iter()
+            .all(|(ski_move, _)| ski_move.kind != SkiMoveKind::Jump));
+
+        // Jumping off the board is still allowed
+        let position: SkiJumps = SkiJumps::from_str("L.|R.").unwrap();
+        assert!(position
+            .left_moves_annotated()
+            .iter()
+            .any(|(ski_move, _)| ski_move.kind == SkiMoveKind::Jump));
+    }
+
     #[test]
     fn winning_ways_examples() {
         // I couldn't find other implementations so we're comparing against positions in winning ways
@@ -330,4 +524,71 @@ mod tests {
         test_canonical_form!("...R.|...L.|.....", "-5/2");
         test_canonical_form!("L....|....R|.....", "1/2");
     }
+
+    /// Same game, but without reductions
+    #[derive(Debug, Clone, Hash, PartialEq, Eq)]
+    struct Unreduced(SkiJumps);
+
+    impl PartizanGame for Unreduced {
+        fn left_moves(&self) -> MoveList<Self> {
+            self.0.left_moves().into_iter().map(Unreduced).collect()
+        }
+
+        fn right_moves(&self) -> MoveList<Self> {
+            self.0.right_moves().into_iter().map(Unreduced).collect()
+        }
+    }
+
+    #[test]
+    fn lazy_moves_match_moves() {
+        let position: SkiJumps = SkiJumps::from_str("L...|.R..|..L.").unwrap();
+        assert_eq!(
+            position.left_moves_iter().collect::<Vec<_>>().as_slice(),
+            position.left_moves().as_slice()
+        );
+        assert_eq!(
+            position.right_moves_iter().collect::<Vec<_>>().as_slice(),
+            position.right_moves().as_slice()
+        );
+    }
+
+    #[test]
+    fn is_symmetric_works() {
+        let transposition_table = ParallelTranspositionTable::new();
+        for (position, symmetric) in [
+            ("L..R", true),
+            ("L...|...R", true),
+            ("L...|..R.", false),
+            ("L..R|R..L", true),
+            ("L...", false),
+        ] {
+            let position: SkiJumps = SkiJumps::from_str(position).unwrap();
+            let value = position.canonical_form(&transposition_table);
+            assert_eq!(value == -&value, symmetric, "{position}");
+            assert_eq!(
+                position.is_symmetric(&transposition_table),
+                symmetric,
+                "{position}"
+            );
+        }
+    }
+
+    #[test]
+    fn reductions_do_not_change_values() {
+        for position in [
+            "...L....|..R.....|........",
+            ".L...|.R...|.....",
+            "L....|....R|.....",
+            "L.R.|..l.|r...",
+            "..L|R..|...|.l.",
+            "L..R|.l..",
+        ] {
+            let position: SkiJumps = SkiJumps::from_str(position).unwrap();
+            assert_eq!(
+                position.canonical_form(&ParallelTranspositionTable::new()),
+                Unreduced(position.clone()).canonical_form(&ParallelTranspositionTable::new()),
+                "{position}"
+            );
+        }
+    }
 }
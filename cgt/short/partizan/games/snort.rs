@@ -3,11 +3,22 @@
 //! vertices in their own color.
 
 use crate::{
+    drawing::svg::{self, ImmSvg, Svg},
     graph::undirected::Graph,
-    numeric::{dyadic_rational_number::DyadicRationalNumber, nimber::Nimber},
-    short::partizan::{canonical_form::CanonicalForm, partizan_game::PartizanGame},
+    numeric::{dyadic_rational_number::DyadicRationalNumber, nimber::Nimber, rational::Rational},
+    short::partizan::{
+        canonical_form::CanonicalForm,
+        partizan_game::{MoveList, PartizanGame},
+        transposition_table::TranspositionTable,
+    },
+};
+use std::{
+    collections::VecDeque,
+    fmt::{self, Write},
+    hash::{Hash, Hasher},
+    num::NonZeroU32,
+    sync::OnceLock,
 };
-use std::{collections::VecDeque, fmt::Write, num::NonZeroU32};
 
 /// Color of Snort vertex. Note that we are taking tinting apporach rather than direct tracking
 /// of adjacent colors.
@@ -83,7 +94,44 @@ impl VertexKind {
     }
 }
 
+/// Lazily computed vertex degrees. Ignored when comparing and hashing positions, as it is
+/// fully determined by the vertices and the graph.
+#[derive(Debug, Clone, Default)]
+struct DegreeCache(OnceLock<Vec<usize>>);
+
+impl PartialEq for DegreeCache {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for DegreeCache {}
+
+impl Hash for DegreeCache {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+/// Summary of a Snort position, see [`Snort::analysis`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SnortAnalysis {
+    /// Canonical form of the position
+    pub canonical_form: CanonicalForm,
+
+    /// Temperature of the position
+    pub temperature: DyadicRationalNumber,
+
+    /// Degree of the position, see [`Snort::degree`]
+    pub degree: usize,
+
+    /// Difference between temperature and degree, used to score positions in genetic search
+    pub fitness: Rational,
+}
+
 /// Position of a [snort](self) game
+///
+/// Vertex degrees are cached and invalidated by mutating methods. After modifying `vertices` or
+/// `graph` directly call [`Snort::invalidate_degrees`].
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Snort {
@@ -92,6 +140,9 @@ pub struct Snort {
 
     /// Get graph of the game. This includes only edges
     pub graph: Graph,
+
+    #[cfg_attr(feature = "serde", serde(skip))]
+    degrees: DegreeCache,
 }
 
 impl Snort {
@@ -100,6 +151,7 @@ impl Snort {
         Self {
             vertices: vec![VertexKind::Single(VertexColor::Empty); graph.size()],
             graph,
+            degrees: DegreeCache::default(),
         }
     }
 
@@ -112,7 +164,106 @@ impl Snort {
             return None;
         }
 
-        Some(Self { vertices, graph })
+        Some(Self {
+            vertices,
+            graph,
+            degrees: DegreeCache::default(),
+        })
+    }
+
+    /// Create a Snort position from a symmetric adjacency matrix and colors of single vertices.
+    /// It's up to the user to ensure that no conflicting colors are connected in the graph.
+    ///
+    /// Returns [None] if matrix is not square or not symmetric, or if number of colors does not
+    /// match the number of vertices.
+    pub fn from_adjacency(matrix: &[Vec<bool>], colors: &[VertexColor]) -> Option<Self> {
+        let size = matrix.len();
+        if colors.len() != size || matrix.iter().any(|row| row.len() != size) {
+            return None;
+        }
+        let symmetric = matrix.iter().enumerate().all(|(v, row)| {
+            row.iter()
+                .enumerate()
+                .all(|(u, edge)| *edge == matrix[u][v])
+        });
+        if !symmetric {
+            return None;
+        }
+
+        Self::with_colors(
+            colors.iter().copied().map(VertexKind::Single).collect(),
+            Graph::from_matrix(size, matrix)?,
+        )
+    }
+
+    /// Create a path position from vertex colors separated by `-`, e.g. `"B-U-R-U"`. `B` is a
+    /// vertex colored blue by Left, `R` colored red by Right, and `U` uncolored. Colored vertices
+    /// are taken and tint their neighbours, as if the players moved there.
+    ///
+    /// Returns [None] if input contains other characters or blue and red vertices are adjacent.
+    pub fn from_path_string(input: &str) -> Option<Self> {
+        let colors = input
+            .split('-')
+            .map(|vertex| match vertex {
+                "B" => Some(Some(VertexColor::TintLeft)),
+                "R" => Some(Some(VertexColor::TintRight)),
+                "U" => Some(None),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+        if colors
+            .windows(2)
+            .any(|pair| pair[0].is_some() && pair[1].is_some() && pair[0] != pair[1])
+        {
+            return None;
+        }
+
+        let edges = (1..colors.len()).map(|v| (v - 1, v)).collect::<Vec<_>>();
+        let mut position = Self::new(Graph::from_edges(colors.len(), &edges));
+        for (vertex, color) in colors.into_iter().enumerate() {
+            if let Some(color) = color {
+                position = position.take_vertex(vertex, color);
+            }
+        }
+        Some(position)
+    }
+
+    /// Add a new empty vertex without any edges and return its index
+    pub fn add_vertex(&mut self) -> usize {
+        self.invalidate_degrees();
+        self.graph.add_vertex();
+        self.vertices.push(VertexKind::Single(VertexColor::Empty));
+        self.vertices.len() - 1
+    }
+
+    /// Connect two vertices with an edge
+    pub fn add_edge(&mut self, lhs_vertex: usize, rhs_vertex: usize) {
+        self.invalidate_degrees();
+        self.graph.add_edge(lhs_vertex, rhs_vertex);
+    }
+
+    /// Remove a vertex together with its edges. Vertices with higher indices are shifted down.
+    pub fn remove_vertex(&mut self, vertex: usize) {
+        self.invalidate_degrees();
+        self.graph.remove_vertex(vertex);
+        self.vertices.remove(vertex);
+    }
+
+    /// Remove an edge between two vertices, if present
+    pub fn remove_edge(&mut self, lhs_vertex: usize, rhs_vertex: usize) {
+        self.invalidate_degrees();
+        self.graph.connect(lhs_vertex, rhs_vertex, false);
+    }
+
+    /// Change color of a vertex, keeping cluster size. It's up to the user to ensure that no
+    /// conflicting colors are connected in the graph.
+    pub fn color_vertex(&mut self, vertex: usize, color: VertexColor) {
+        *self.vertices[vertex].color_mut() = color;
+    }
+
+    /// Drop cached vertex degrees. Needed only after modifying `vertices` or `graph` directly.
+    pub fn invalidate_degrees(&mut self) {
+        self.degrees = DegreeCache::default();
     }
 
     /// Construct new position on caterpillar `C(n+1, n, n+1)`
@@ -169,14 +320,24 @@ impl Snort {
         res
     }
 
+    /// Get degrees of all vertices, correctly counting clusters of vertices. Degrees are computed
+    /// once and cached until the next mutation.
+    pub fn vertex_degrees(&self) -> &[usize] {
+        self.degrees.0.get_or_init(|| {
+            self.graph
+                .vertices()
+                .map(|v| self.vertex_degree(v))
+                .collect()
+        })
+    }
+
     /// Get degree of the underlying game graph, correctly counting clusters of vertices
     ///
     /// Note that using [`Graph::degree`] will yield incorrect results
     pub fn degree(&self) -> usize {
-        self.graph
-            .vertices()
-            .into_iter()
-            .map(|v| self.vertex_degree(v))
+        *self
+            .vertex_degrees()
+            .iter()
             .max()
             .expect("graph to have at least 1 vertex")
     }
@@ -194,78 +355,106 @@ impl Snort {
             .expect("graph to have at least 1 vertex")
     }
 
-    /// Get moves for a given player. Works only for `TintLeft` and `TintRight`.
-    /// Any other input is undefined.
-    fn moves_for<const COLOR: u8>(&self) -> Vec<Self> {
-        // const ADT generics are unsable, so here we go
-        let own_tint_color: VertexColor = VertexColor::try_from(COLOR).unwrap();
-
-        let mut moves = Vec::with_capacity(self.graph.size());
+    /// Compute canonical form, temperature, degree, and fitness of the position
+    ///
+    /// # Panics
+    /// - Graph has no vertices
+    pub fn analysis<TT>(&self, transposition_table: &TT) -> SnortAnalysis
+    where
+        TT: TranspositionTable<Self> + Sync,
+    {
+        let canonical_form = self.canonical_form(transposition_table);
+        let temperature = canonical_form.temperature();
+        let degree = self.degree();
+        let fitness = temperature.to_rational() - Rational::from(degree as i64);
+        SnortAnalysis {
+            canonical_form,
+            temperature,
+            degree,
+            fitness,
+        }
+    }
 
-        // Vertices where player can move
-        let move_vertices = self
-            .vertices
-            .iter()
-            .enumerate()
-            .filter(|(_, vertex)| {
-                let vertex_color = vertex.color();
-                vertex_color == own_tint_color || vertex_color == VertexColor::Empty
-            })
-            .map(|(idx, _)| idx);
+    /// Check if the position cannot be split into a sum of independent positions
+    ///
+    /// Note that this is different than [`Graph::is_connected`] as taken vertices are ignored
+    pub fn is_connected(&self) -> bool {
+        self.decompositions().len() <= 1
+    }
 
-        // Go through list of vertices with legal move
-        for move_vertex_idx in move_vertices {
-            let mut position: Self = self.clone();
+    /// Get position after a player with `own_tint_color` takes `move_vertex_idx`. Works only for
+    /// `TintLeft` and `TintRight` and vertices that are empty or tinted in the player's color.
+    /// Any other input is undefined.
+    pub fn take_vertex(&self, move_vertex_idx: usize, own_tint_color: VertexColor) -> Self {
+        let mut position: Self = self.clone();
+        position.invalidate_degrees();
 
-            // Take vertex
-            let move_vertex = &mut position.vertices[move_vertex_idx];
-            match move_vertex {
-                VertexKind::Single(move_vertex_color) => *move_vertex_color = VertexColor::Taken,
-                VertexKind::Cluster(_, cluster_size) => {
-                    if *cluster_size == NonZeroU32::new(1).unwrap() {
-                        *move_vertex = VertexKind::Single(VertexColor::Taken);
-                    } else {
-                        // Vertices in cluster are disconnected so nothing changes color
-                        *cluster_size = NonZeroU32::new(cluster_size.get() - 1).unwrap();
-                    }
+        // Take vertex
+        let move_vertex = &mut position.vertices[move_vertex_idx];
+        match move_vertex {
+            VertexKind::Single(move_vertex_color) => *move_vertex_color = VertexColor::Taken,
+            VertexKind::Cluster(_, cluster_size) => {
+                if *cluster_size == NonZeroU32::new(1).unwrap() {
+                    *move_vertex = VertexKind::Single(VertexColor::Taken);
+                } else {
+                    // Vertices in cluster are disconnected so nothing changes color
+                    *cluster_size = NonZeroU32::new(cluster_size.get() - 1).unwrap();
                 }
             }
+        }
 
-            // Disconnect `move_vertex` from adjecent vertices and tint them
-            for adjacent_vertex_idx in self.graph.adjacent_to(move_vertex_idx) {
-                // Disconnect move vertex from adjecent, we disconnect only single vertices
-                // because clusters are still alive. If cluster is dead it's turned into single
-                // before (See: 'take vertex' above), so it still works.
-                if let VertexKind::Single(_) = position.vertices[move_vertex_idx] {
-                    position
-                        .graph
-                        .connect(move_vertex_idx, adjacent_vertex_idx, false);
-                }
+        // Disconnect `move_vertex` from adjecent vertices and tint them
+        for adjacent_vertex_idx in self.graph.adjacent_to(move_vertex_idx) {
+            // Disconnect move vertex from adjecent, we disconnect only single vertices
+            // because clusters are still alive. If cluster is dead it's turned into single
+            // before (See: 'take vertex' above), so it still works.
+            if let VertexKind::Single(_) = position.vertices[move_vertex_idx] {
+                position
+                    .graph
+                    .connect(move_vertex_idx, adjacent_vertex_idx, false);
+            }
 
-                // No loops in snort graphs
-                if adjacent_vertex_idx != move_vertex_idx {
-                    let adjacent_vertex = &mut position.vertices[adjacent_vertex_idx];
-                    let adjacent_vertex_color = adjacent_vertex.color_mut();
+            // No loops in snort graphs
+            if adjacent_vertex_idx != move_vertex_idx {
+                let adjacent_vertex = &mut position.vertices[adjacent_vertex_idx];
+                let adjacent_vertex_color = adjacent_vertex.color_mut();
 
-                    // Tint adjacent vertex
-                    if *adjacent_vertex_color == own_tint_color
-                        || *adjacent_vertex_color == VertexColor::Empty
-                    {
-                        // If adjacent vertex is empty or tinted in own color, tint it in own
-                        *adjacent_vertex_color = own_tint_color;
-                    } else {
-                        // Otherwise the vertex is tinted in opponents color, so no one can longer
-                        // move there, thus we mark is as taken and disconnect from the graph
-                        *adjacent_vertex_color = VertexColor::Taken;
-                        for v in position.graph.vertices() {
-                            position.graph.connect(v, adjacent_vertex_idx, false);
-                        }
+                // Tint adjacent vertex
+                if *adjacent_vertex_color == own_tint_color
+                    || *adjacent_vertex_color == VertexColor::Empty
+                {
+                    // If adjacent vertex is empty or tinted in own color, tint it in own
+                    *adjacent_vertex_color = own_tint_color;
+                } else {
+                    // Otherwise the vertex is tinted in opponents color, so no one can longer
+                    // move there, thus we mark is as taken and disconnect from the graph
+                    *adjacent_vertex_color = VertexColor::Taken;
+                    for v in position.graph.vertices() {
+                        position.graph.connect(v, adjacent_vertex_idx, false);
                     }
                 }
             }
-            moves.push(position);
         }
-        moves
+
+        position
+    }
+
+    /// Get moves for a given player. Works only for `TintLeft` and `TintRight`.
+    /// Any other input is undefined.
+    fn moves_for<const COLOR: u8>(&self) -> MoveList<Self> {
+        // const ADT generics are unsable, so here we go
+        let own_tint_color: VertexColor = VertexColor::try_from(COLOR).unwrap();
+
+        // Go through list of vertices with legal move
+        self.vertices
+            .iter()
+            .enumerate()
+            .filter(|(_, vertex)| {
+                let vertex_color = vertex.color();
+                vertex_color == own_tint_color || vertex_color == VertexColor::Empty
+            })
+            .map(|(move_vertex_idx, _)| self.take_vertex(move_vertex_idx, own_tint_color))
+            .collect()
     }
 
     /// BFS search to get the decompisitons, should be used only as a helper for [`Self::decompositions`]
@@ -304,6 +493,7 @@ impl Snort {
         Self {
             vertices: new_vertices,
             graph: new_graph,
+            degrees: DegreeCache::default(),
         }
     }
 
@@ -351,6 +541,277 @@ impl Snort {
         write!(buf, "}}").unwrap();
         buf
     }
+
+    /// Read position rendered with [`Snort::to_graphviz`]. Vertices that are missing in the input
+    /// are taken, but taken vertices without edges after the last rendered vertex are lost.
+    ///
+    /// Returns [None] if input is not in the format produced by [`Snort::to_graphviz`].
+    pub fn from_graphviz(input: &str) -> Option<Self> {
+        let body = input.trim().strip_prefix("graph G {")?.strip_suffix('}')?;
+
+        let mut vertices: Vec<Option<VertexKind>> = Vec::new();
+        let mut edges = Vec::new();
+        for statement in body.split(';').map(str::trim).filter(|s| !s.is_empty()) {
+            if let Some((v, u)) = statement.split_once("--") {
+                edges.push((v.trim().parse().ok()?, u.trim().parse().ok()?));
+                continue;
+            }
+
+            let (vertex_idx, attributes) = statement.split_once('[')?;
+            let vertex_idx: usize = vertex_idx.trim().parse().ok()?;
+            let mut label = None;
+            let mut color = None;
+            let mut shape = None;
+            for attribute in attributes.strip_suffix(']')?.split(',') {
+                let (key, value) = attribute.split_once('=')?;
+                match key.trim() {
+                    "label" => label = Some(value.trim().trim_matches('"')),
+                    "fillcolor" => color = Some(value.trim()),
+                    "shape" => shape = Some(value.trim()),
+                    _ => {}
+                }
+            }
+
+            let color = match color? {
+                "white" => VertexColor::Empty,
+                "blue" => VertexColor::TintLeft,
+                "red" => VertexColor::TintRight,
+                _ => return None,
+            };
+            let vertex = match shape? {
+                "circle" => VertexKind::Single(color),
+                "square" => {
+                    let (_, cluster_size) = label?.split_once("\\n<")?;
+                    VertexKind::Cluster(color, cluster_size.strip_suffix('>')?.parse().ok()?)
+                }
+                _ => return None,
+            };
+
+            if vertices.len() <= vertex_idx {
+                vertices.resize(vertex_idx + 1, None);
+            }
+            vertices[vertex_idx] = Some(vertex);
+        }
+
+        let size = edges
+            .iter()
+            .map(|&(v, u): &(usize, usize)| v.max(u) + 1)
+            .fold(vertices.len(), usize::max);
+        vertices.resize(size, None);
+
+        Self::with_colors(
+            vertices
+                .into_iter()
+                .map(|vertex| vertex.unwrap_or(VertexKind::Single(VertexColor::Taken)))
+                .collect(),
+            Graph::from_edges(size, &edges),
+        )
+    }
+}
+
+impl Svg for Snort {
+    /// Render vertices on a circle, without relying on external layout engine
+    fn to_svg<W>(&self, buf: &mut W) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        // Chosen arbitrarily
+        let vertex_radius = 24;
+        let stroke_width = 2;
+        let layout_radius = (self.vertices.len() as u32 * vertex_radius).max(2 * vertex_radius);
+
+        let center = (layout_radius + vertex_radius + stroke_width) as i32;
+        let svg_size = 2 * center as u32;
+
+        let positions = self
+            .graph
+            .vertices()
+            .map(|v| {
+                let angle = 2.0 * std::f64::consts::PI * v as f64 / self.vertices.len() as f64;
+                (
+                    center + (layout_radius as f64 * angle.sin()).round() as i32,
+                    center - (layout_radius as f64 * angle.cos()).round() as i32,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        ImmSvg::new(buf, svg_size, svg_size, |buf| {
+            ImmSvg::g(buf, "black", |buf| {
+                for v in self.graph.vertices() {
+                    for u in self.graph.vertices() {
+                        if v < u && self.graph.are_adjacent(v, u) {
+                            let (x1, y1) = positions[v];
+                            let (x2, y2) = positions[u];
+                            ImmSvg::line(buf, x1, y1, x2, y2, stroke_width)?;
+                        }
+                    }
+                }
+                Ok(())
+            })?;
+
+            for (vertex_idx, vertex) in self.vertices.iter().enumerate() {
+                let fill = match vertex.color() {
+                    VertexColor::Empty => "white",
+                    VertexColor::TintLeft => "blue",
+                    VertexColor::TintRight => "red",
+                    VertexColor::Taken => "gray",
+                };
+                let (cx, cy) = positions[vertex_idx];
+                let circle = svg::Circle {
+                    cx,
+                    cy,
+                    r: vertex_radius,
+                    stroke: "black".into(),
+                    stroke_width,
+                    fill: fill.into(),
+                };
+                ImmSvg::circle(buf, &circle)?;
+
+                let text = svg::Text {
+                    x: cx,
+                    y: cy + 6,
+                    text: match vertex {
+                        VertexKind::Single(_) => format!("{vertex_idx}"),
+                        VertexKind::Cluster(_, cluster_size) => {
+                            format!("{vertex_idx}&lt;{}&gt;", cluster_size.get())
+                        }
+                    },
+                    text_anchor: svg::TextAnchor::Middle,
+                };
+                ImmSvg::text(buf, &text)?;
+            }
+
+            Ok(())
+        })
+    }
+}
+
+#[test]
+fn svg_has_circle_per_vertex() {
+    let mut snort = Snort::new_three_caterpillar(NonZeroU32::new(3).unwrap());
+    snort.color_vertex(1, VertexColor::TintLeft);
+    snort.color_vertex(2, VertexColor::TintRight);
+
+    let mut buf = String::new();
+    snort.to_svg(&mut buf).unwrap();
+    assert!(buf.starts_with("<svg"));
+    assert_eq!(buf.matches("<circle").count(), snort.vertices.len());
+    assert_eq!(buf.matches("<line").count(), 5);
+    assert!(buf.contains("fill=\"blue\""));
+    assert!(buf.contains("fill=\"red\""));
+}
+
+#[test]
+fn graphviz_roundtrip() {
+    let mut snort = Snort::new_three_caterpillar(NonZeroU32::new(3).unwrap());
+    snort.color_vertex(1, VertexColor::TintLeft);
+    snort.color_vertex(5, VertexColor::TintRight);
+    assert_eq!(
+        Snort::from_graphviz(&snort.to_graphviz()),
+        Some(snort.clone())
+    );
+
+    let taken = snort.take_vertex(0, VertexColor::TintLeft);
+    assert_eq!(taken.vertices[0], VertexKind::Single(VertexColor::Taken));
+    assert_eq!(Snort::from_graphviz(&taken.to_graphviz()), Some(taken));
+
+    assert_eq!(Snort::from_graphviz("graph G {0 -- 1;"), None);
+    assert_eq!(
+        Snort::from_graphviz("graph G {0 [label=\"0\", fillcolor=green, shape=circle];}"),
+        None
+    );
+}
+
+#[test]
+fn from_adjacency_works() {
+    let triangle = vec![
+        vec![false, true, true],
+        vec![true, false, true],
+        vec![true, true, false],
+    ];
+    let colors = [
+        VertexColor::Empty,
+        VertexColor::TintLeft,
+        VertexColor::TintLeft,
+    ];
+    let snort = Snort::from_adjacency(&triangle, &colors).unwrap();
+    assert_eq!(snort.vertex_degrees(), &[2, 2, 2]);
+    assert_eq!(snort.vertices[1], VertexKind::Single(VertexColor::TintLeft));
+    assert_eq!(
+        snort,
+        Snort::with_colors(
+            colors.iter().copied().map(VertexKind::Single).collect(),
+            Graph::from_edges(3, &[(0, 1), (0, 2), (1, 2)])
+        )
+        .unwrap()
+    );
+
+    // Not symmetric
+    let directed = vec![vec![false, true], vec![false, false]];
+    assert_eq!(Snort::from_adjacency(&directed, &colors[..2]), None);
+
+    // Not square
+    assert_eq!(Snort::from_adjacency(&triangle[..2], &colors[..2]), None);
+
+    // Wrong number of colors
+    assert_eq!(Snort::from_adjacency(&triangle, &colors[..2]), None);
+}
+
+#[test]
+fn from_path_string_works() {
+    use crate::short::partizan::transposition_table::ParallelTranspositionTable;
+
+    let snort = Snort::from_path_string("B-U-R-U").unwrap();
+    assert_eq!(
+        snort.vertices,
+        vec![
+            VertexKind::Single(VertexColor::Taken),
+            VertexKind::Single(VertexColor::Taken),
+            VertexKind::Single(VertexColor::Taken),
+            VertexKind::Single(VertexColor::TintRight),
+        ]
+    );
+
+    let transposition_table = ParallelTranspositionTable::new();
+    let value = |input| {
+        Snort::from_path_string(input)
+            .unwrap()
+            .canonical_form(&transposition_table)
+            .to_string()
+    };
+    assert_eq!(value("B-U-R-U"), "-1");
+    assert_eq!(value("U-U"), "{1|-1}");
+    assert_eq!(value("B-U-U"), "{1|0}");
+
+    assert_eq!(Snort::from_path_string("B-R"), None);
+    assert_eq!(Snort::from_path_string("B-X"), None);
+    assert_eq!(Snort::from_path_string(""), None);
+}
+
+#[test]
+fn is_symmetric_works() {
+    use crate::short::partizan::transposition_table::ParallelTranspositionTable;
+
+    let transposition_table = ParallelTranspositionTable::new();
+    let check = |input| {
+        let snort = Snort::from_path_string(input).unwrap();
+        let value = snort.canonical_form(&transposition_table);
+        assert_eq!(
+            snort.is_symmetric(&transposition_table),
+            value == -&value,
+            "{input}"
+        );
+        (snort.is_symmetric(&transposition_table), value.to_string())
+    };
+
+    // No tinted vertices
+    assert_eq!(check("U-U-U"), (true, "{2|-2}".to_owned()));
+
+    // Tinted, but colors are swapped by reversing the path
+    assert_eq!(check("B-U-U-R"), (true, "*".to_owned()));
+
+    assert_eq!(check("B-U"), (false, "1".to_owned()));
+    assert_eq!(check("B-U-U"), (false, "{1|0}".to_owned()));
 }
 
 #[test]
@@ -362,12 +823,75 @@ fn degree_works() {
     assert_eq!(snort.degree(), 12);
 }
 
+#[test]
+fn analysis_works() {
+    use crate::short::partizan::transposition_table::ParallelTranspositionTable;
+
+    let transposition_table = ParallelTranspositionTable::new();
+    let snort = Snort::new(Graph::from_edges(3, &[(0, 1), (1, 2)]));
+    let analysis = snort.analysis(&transposition_table);
+    assert_eq!(analysis.canonical_form.to_string(), "{2|-2}");
+    assert_eq!(analysis.temperature, DyadicRationalNumber::from(2));
+    assert_eq!(analysis.degree, 2);
+    assert_eq!(analysis.fitness, Rational::from(0));
+}
+
+#[test]
+fn mutations_work() {
+    let mut snort = Snort::new(Graph::from_edges(2, &[(0, 1)]));
+    let v = snort.add_vertex();
+    assert_eq!(v, 2);
+    assert_eq!(snort.vertices.len(), 3);
+    assert!(!snort.graph.are_adjacent(1, v));
+
+    snort.add_edge(1, v);
+    assert!(snort.graph.are_adjacent(1, v));
+    assert!(snort.graph.are_adjacent(v, 1));
+
+    snort.remove_edge(0, 1);
+    assert!(!snort.graph.are_adjacent(0, 1));
+    assert!(snort.graph.are_adjacent(1, v));
+
+    snort.color_vertex(v, VertexColor::TintLeft);
+    assert_eq!(snort.vertices[v], VertexKind::Single(VertexColor::TintLeft));
+}
+
+#[test]
+fn cached_degrees_match_recomputation() {
+    let mut snort = Snort::new_three_caterpillar(NonZeroU32::new(3).unwrap());
+    let fresh = |snort: &Snort| {
+        snort
+            .graph
+            .vertices()
+            .map(|v| snort.vertex_degree(v))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(snort.vertex_degrees(), fresh(&snort));
+
+    let v = snort.add_vertex();
+    assert_eq!(snort.vertex_degrees(), fresh(&snort));
+    snort.add_edge(0, v);
+    snort.add_edge(4, v);
+    assert_eq!(snort.vertex_degrees(), fresh(&snort));
+    snort.remove_edge(0, 1);
+    assert_eq!(snort.vertex_degrees(), fresh(&snort));
+
+    let child = snort.take_vertex(4, VertexColor::TintLeft);
+    assert_eq!(child.vertex_degrees(), fresh(&child));
+    assert!(snort.left_moves().contains(&child));
+
+    snort.add_edge(2, 3);
+    assert_eq!(snort.vertex_degrees(), fresh(&snort));
+    snort.remove_vertex(1);
+    assert_eq!(snort.vertex_degrees(), fresh(&snort));
+}
+
 impl PartizanGame for Snort {
-    fn left_moves(&self) -> Vec<Self> {
+    fn left_moves(&self) -> MoveList<Self> {
         self.moves_for::<{ VertexColor::TintLeft as u8 }>()
     }
 
-    fn right_moves(&self) -> Vec<Self> {
+    fn right_moves(&self) -> MoveList<Self> {
         self.moves_for::<{ VertexColor::TintRight as u8 }>()
     }
 
@@ -401,6 +925,24 @@ impl PartizanGame for Snort {
         res
     }
 
+    /// Positions without tinted vertices are unchanged by swapping players, so they are
+    /// symmetric without computing the canonical form
+    fn is_symmetric<TT>(&self, transposition_table: &TT) -> bool
+    where
+        TT: TranspositionTable<Self> + Sync,
+    {
+        if self
+            .vertices
+            .iter()
+            .all(|vertex| matches!(vertex.color(), VertexColor::Empty | VertexColor::Taken))
+        {
+            return true;
+        }
+
+        let value = self.canonical_form(transposition_table);
+        value == -&value
+    }
+
     fn reductions(&self) -> Option<CanonicalForm> {
         if let &[vertex] = &self.vertices[..] {
             let cf = match vertex {
@@ -432,8 +974,8 @@ impl PartizanGame for Snort {
 #[test]
 fn no_moves() {
     let position = Snort::new(Graph::empty(0));
-    assert_eq!(position.left_moves(), vec![]);
-    assert_eq!(position.right_moves(), vec![]);
+    assert!(position.left_moves().is_empty());
+    assert!(position.right_moves().is_empty());
 }
 
 #[test]
@@ -464,6 +1006,48 @@ fn correct_canonical_forms() {
     assert_eq!(canonical_form.to_string(), "*");
 }
 
+#[test]
+fn two_stars_decompose() {
+    use crate::short::partizan::{
+        canonical_form::Moves, transposition_table::ParallelTranspositionTable,
+    };
+
+    let position = Snort::new(Graph::from_edges(
+        7,
+        &[(0, 1), (0, 2), (3, 4), (3, 5), (3, 6)],
+    ));
+    assert!(!position.is_connected());
+
+    let components = position.decompositions();
+    assert_eq!(
+        components,
+        vec![
+            Snort::new(Graph::from_edges(3, &[(0, 1), (0, 2)])),
+            Snort::new(Graph::from_edges(4, &[(0, 1), (0, 2), (0, 3)])),
+        ]
+    );
+    assert!(components.iter().all(Snort::is_connected));
+
+    let transposition_table = ParallelTranspositionTable::new();
+    let sum = components
+        .iter()
+        .map(|component| component.canonical_form(&transposition_table))
+        .sum::<CanonicalForm>();
+    let whole = CanonicalForm::new_from_moves(Moves {
+        left: position
+            .left_moves()
+            .iter()
+            .map(|m| m.canonical_form(&transposition_table))
+            .collect(),
+        right: position
+            .right_moves()
+            .iter()
+            .map(|m| m.canonical_form(&transposition_table))
+            .collect(),
+    });
+    assert_eq!(whole, sum);
+}
+
 #[test]
 fn correct_sensible() {
     use crate::short::partizan::transposition_table::ParallelTranspositionTable;
@@ -7,8 +7,8 @@
 
 use crate::{
     drawing::svg::{self, ImmSvg, Svg},
-    grid::CharTile,
-    short::partizan::partizan_game::PartizanGame,
+    grid::{CharTile, GridParseError},
+    short::partizan::partizan_game::{MoveList, PartizanGame},
 };
 use cgt_derive::Tile;
 use std::{
@@ -48,12 +48,12 @@ impl ToadsAndFrogs {
 }
 
 impl FromStr for ToadsAndFrogs {
-    type Err = ();
+    type Err = GridParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut tiles = Vec::with_capacity(s.len());
         for c in s.chars() {
-            tiles.push(Tile::char_to_tile(c).ok_or(())?);
+            tiles.push(Tile::char_to_tile(c).ok_or(GridParseError::InvalidChar(c))?);
         }
         Ok(Self::new(tiles))
     }
@@ -125,11 +125,11 @@ impl Svg for ToadsAndFrogs {
 }
 
 impl PartizanGame for ToadsAndFrogs {
-    fn left_moves(&self) -> Vec<Self> {
+    fn left_moves(&self) -> MoveList<Self> {
         let own = Tile::Toad;
         let opponent = Tile::Frog;
 
-        let mut moves = Vec::new();
+        let mut moves = MoveList::new();
 
         for (idx, tile) in self.tiles.iter().copied().enumerate() {
             if tile == own {
@@ -153,11 +153,11 @@ impl PartizanGame for ToadsAndFrogs {
         moves
     }
 
-    fn right_moves(&self) -> Vec<Self> {
+    fn right_moves(&self) -> MoveList<Self> {
         let own = Tile::Frog;
         let opponent = Tile::Toad;
 
-        let mut moves = Vec::new();
+        let mut moves = MoveList::new();
 
         for (idx, tile) in self.tiles.iter().copied().enumerate() {
             if tile == own {
@@ -211,15 +211,30 @@ mod tests {
 
     #[test]
     fn left_moves() {
-        assert_eq!(row!("T.TFTFF").left_moves(), vec![row!(".TTFTFF")]);
-        assert_eq!(row!("TFT.TFF").left_moves(), vec![row!("TF.TTFF")]);
+        assert_eq!(
+            row!("T.TFTFF").left_moves().as_slice(),
+            vec![row!(".TTFTFF")]
+        );
+        assert_eq!(
+            row!("TFT.TFF").left_moves().as_slice(),
+            vec![row!("TF.TTFF")]
+        );
     }
 
     #[test]
     fn right_moves() {
-        assert_eq!(row!("T.TFTFF").right_moves(), vec![row!("TFT.TFF")]);
-        assert_eq!(row!(".F.F").right_moves(), vec![row!("F..F"), row!(".FF.")]);
-        assert_eq!(row!("TFT.TFF").right_moves(), vec![row!("TFTFT.F")]);
+        assert_eq!(
+            row!("T.TFTFF").right_moves().as_slice(),
+            vec![row!("TFT.TFF")]
+        );
+        assert_eq!(
+            row!(".F.F").right_moves().as_slice(),
+            vec![row!("F..F"), row!(".FF.")]
+        );
+        assert_eq!(
+            row!("TFT.TFF").right_moves().as_slice(),
+            vec![row!("TFTFT.F")]
+        );
     }
 
     #[test]
@@ -0,0 +1,113 @@
+//! Winners of a game with perfect play
+
+use crate::short::partizan::{
+    canonical_form::CanonicalForm,
+    partizan_game::{GridEnumerable, PartizanGame},
+    transposition_table::TranspositionTable,
+};
+use std::fmt::{self, Display};
+
+/// One of the two players
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Player {
+    /// Left player
+    Left,
+
+    /// Right player
+    Right,
+}
+
+impl Display for Player {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Left => write!(f, "Left"),
+            Self::Right => write!(f, "Right"),
+        }
+    }
+}
+
+/// Winner of the game with perfect play, depending on who moves first
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Outcome {
+    /// Winner when Left moves first
+    pub left_first: Player,
+
+    /// Winner when Right moves first
+    pub right_first: Player,
+}
+
+impl Outcome {
+    /// Get outcome of a game from its value
+    pub fn of(game: &CanonicalForm) -> Self {
+        let zero = CanonicalForm::new_integer(0);
+        Self {
+            left_first: if game <= &zero {
+                Player::Right
+            } else {
+                Player::Left
+            },
+            right_first: if game >= &zero {
+                Player::Left
+            } else {
+                Player::Right
+            },
+        }
+    }
+}
+
+/// Count positions of a given size in each outcome class, in order: Left wins (L), Right wins
+/// (R), first player wins (N), second player wins (P)
+pub fn outcome_distribution<G, TT>(width: u8, height: u8, transposition_table: &TT) -> [usize; 4]
+where
+    G: PartizanGame + GridEnumerable,
+    TT: TranspositionTable<G> + Sync,
+{
+    let mut distribution = [0; 4];
+    for position in G::positions_of_size(width, height) {
+        let outcome = position.who_wins(transposition_table);
+        let class = match (outcome.left_first, outcome.right_first) {
+            (Player::Left, Player::Left) => 0,
+            (Player::Right, Player::Right) => 1,
+            (Player::Left, Player::Right) => 2,
+            (Player::Right, Player::Left) => 3,
+        };
+        distribution[class] += 1;
+    }
+    distribution
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn outcome_works() {
+        let outcome = |s| Outcome::of(&CanonicalForm::from_str(s).unwrap());
+        let outcome_eq = |left_first, right_first| Outcome {
+            left_first,
+            right_first,
+        };
+
+        assert_eq!(outcome("*"), outcome_eq(Player::Left, Player::Right));
+        assert_eq!(outcome("1"), outcome_eq(Player::Left, Player::Left));
+        assert_eq!(outcome("-1/2"), outcome_eq(Player::Right, Player::Right));
+        assert_eq!(outcome("0"), outcome_eq(Player::Right, Player::Left));
+        assert_eq!(outcome("{1|-1}"), outcome_eq(Player::Left, Player::Right));
+    }
+
+    #[test]
+    fn outcome_distribution_works() {
+        use crate::short::partizan::{
+            games::domineering::Domineering, transposition_table::ParallelTranspositionTable,
+        };
+
+        let transposition_table = ParallelTranspositionTable::new();
+        assert_eq!(
+            outcome_distribution::<Domineering, _>(2, 2, &transposition_table),
+            [2, 2, 5, 7]
+        );
+    }
+}
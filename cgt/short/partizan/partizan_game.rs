@@ -1,26 +1,282 @@
 //! Shared traits for short partizan games
 
 use crate::{
-    numeric::rational::Rational,
+    numeric::{dyadic_rational_number::DyadicRationalNumber, rational::Rational},
     short::partizan::{
         canonical_form::{CanonicalForm, Moves},
+        outcome::Outcome,
+        symmetry::Symmetry,
         thermograph::Thermograph,
         trajectory::Trajectory,
-        transposition_table::TranspositionTable,
+        transposition_table::{ParallelTranspositionTable, TranspositionTable},
     },
 };
+use ahash::{HashMap, HashMapExt, HashSet, HashSetExt};
 use std::hash::Hash;
 
 #[cfg(feature = "rayon")]
-use rayon::prelude::{IntoParallelIterator, ParallelIterator};
+use rayon::prelude::{IntoParallelIterator, IntoParallelRefIterator, ParallelIterator};
+
+#[cfg(not(feature = "smallvec"))]
+type MoveListStorage<G> = Vec<G>;
+
+#[cfg(feature = "smallvec")]
+type MoveListStorage<G> = smallvec::SmallVec<[G; 8]>;
+
+/// List of moves of a [`PartizanGame`]. With `smallvec` feature up to 8 moves are stored inline
+/// without heap allocation. The storage is private, so the type and its API are the same with
+/// and without the feature.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MoveList<G> {
+    moves: MoveListStorage<G>,
+}
+
+impl<G> Default for MoveList<G> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<G> MoveList<G> {
+    /// Create new empty move list
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            moves: MoveListStorage::new(),
+        }
+    }
+
+    /// Create new empty move list with space for at least `capacity` moves
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            moves: MoveListStorage::with_capacity(capacity),
+        }
+    }
+
+    /// Append a move to the end of the list
+    #[inline]
+    pub fn push(&mut self, position: G) {
+        self.moves.push(position);
+    }
+
+    /// Keep only the first `len` moves
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        self.moves.truncate(len);
+    }
+
+    /// Remove consecutive duplicate moves
+    #[inline]
+    pub fn dedup(&mut self)
+    where
+        G: PartialEq,
+    {
+        self.moves.dedup();
+    }
+
+    /// View the moves as a slice
+    #[inline]
+    pub fn as_slice(&self) -> &[G] {
+        &self.moves
+    }
+
+    /// Convert to a [`Vec`], allocating only if the moves are stored inline
+    #[inline]
+    pub fn into_vec(self) -> Vec<G> {
+        #[cfg(not(feature = "smallvec"))]
+        return self.moves;
+
+        #[cfg(feature = "smallvec")]
+        return self.moves.into_vec();
+    }
+}
+
+impl<G> std::ops::Deref for MoveList<G> {
+    type Target = [G];
+
+    #[inline]
+    fn deref(&self) -> &[G] {
+        &self.moves
+    }
+}
+
+impl<G> std::ops::DerefMut for MoveList<G> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [G] {
+        &mut self.moves
+    }
+}
+
+impl<G> FromIterator<G> for MoveList<G> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = G>>(iter: I) -> Self {
+        Self {
+            moves: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<G> Extend<G> for MoveList<G> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = G>>(&mut self, iter: I) {
+        self.moves.extend(iter);
+    }
+}
+
+impl<G> From<Vec<G>> for MoveList<G> {
+    #[inline]
+    fn from(moves: Vec<G>) -> Self {
+        Self {
+            moves: MoveListStorage::from(moves),
+        }
+    }
+}
+
+impl<G> From<MoveList<G>> for Vec<G> {
+    #[inline]
+    fn from(moves: MoveList<G>) -> Self {
+        moves.into_vec()
+    }
+}
+
+impl<G> PartialEq<Vec<G>> for MoveList<G>
+where
+    G: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Vec<G>) -> bool {
+        **self == **other
+    }
+}
+
+/// Owning iterator over a [`MoveList`]
+#[derive(Debug)]
+pub struct MoveListIntoIter<G> {
+    #[cfg(not(feature = "smallvec"))]
+    inner: std::vec::IntoIter<G>,
+    #[cfg(feature = "smallvec")]
+    inner: smallvec::IntoIter<[G; 8]>,
+}
+
+impl<G> Iterator for MoveListIntoIter<G> {
+    type Item = G;
+
+    #[inline]
+    fn next(&mut self) -> Option<G> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<G> DoubleEndedIterator for MoveListIntoIter<G> {
+    #[inline]
+    fn next_back(&mut self) -> Option<G> {
+        self.inner.next_back()
+    }
+}
+
+impl<G> ExactSizeIterator for MoveListIntoIter<G> {}
+
+impl<G> IntoIterator for MoveList<G> {
+    type Item = G;
+    type IntoIter = MoveListIntoIter<G>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        MoveListIntoIter {
+            inner: self.moves.into_iter(),
+        }
+    }
+}
+
+impl<'a, G> IntoIterator for &'a MoveList<G> {
+    type Item = &'a G;
+    type IntoIter = std::slice::Iter<'a, G>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.moves.iter()
+    }
+}
 
 /// A short partizan game
 pub trait PartizanGame: Sized + Clone + Hash + Send + Sync + Eq {
     /// List of all moves for the Left player
-    fn left_moves(&self) -> Vec<Self>;
+    fn left_moves(&self) -> MoveList<Self>;
 
     /// List of all moves for the Right player
-    fn right_moves(&self) -> Vec<Self>;
+    fn right_moves(&self) -> MoveList<Self>;
+
+    /// Lazily generated moves for the Left player, useful when only some of the moves are needed.
+    ///
+    /// Default implementation iterates over [`PartizanGame::left_moves`]. Games may override it to
+    /// generate moves on demand, but must yield the same moves as [`PartizanGame::left_moves`],
+    /// each once, possibly in a different order.
+    fn left_moves_iter(&self) -> impl Iterator<Item = Self> {
+        self.left_moves().into_iter()
+    }
+
+    /// Lazily generated moves for the Right player
+    ///
+    /// See [`PartizanGame::left_moves_iter`]
+    fn right_moves_iter(&self) -> impl Iterator<Item = Self> {
+        self.right_moves().into_iter()
+    }
+
+    /// Number of moves for the Left player
+    ///
+    /// Default implementation is `left_moves().len()`. Games may override it with a cheaper count,
+    /// which must be equal to the number of moves returned by [`PartizanGame::left_moves`].
+    fn num_left_moves(&self) -> usize {
+        self.left_moves().len()
+    }
+
+    /// Number of moves for the Right player
+    ///
+    /// See [`PartizanGame::num_left_moves`]
+    fn num_right_moves(&self) -> usize {
+        self.right_moves().len()
+    }
+
+    /// List of moves for the Left player where moves equal under symmetry are merged. Every move
+    /// is replaced by its [`Symmetry::canonical_symmetry`].
+    fn left_moves_dedup_symmetry(&self) -> Vec<Self>
+    where
+        Self: Symmetry,
+    {
+        dedup_symmetry(&self.left_moves())
+    }
+
+    /// List of moves for the Right player where moves equal under symmetry are merged.
+    ///
+    /// See [`PartizanGame::left_moves_dedup_symmetry`]
+    fn right_moves_dedup_symmetry(&self) -> Vec<Self>
+    where
+        Self: Symmetry,
+    {
+        dedup_symmetry(&self.right_moves())
+    }
+
+    /// Check if the Left player has no moves
+    fn is_left_terminal(&self) -> bool {
+        self.left_moves().is_empty()
+    }
+
+    /// Check if the Right player has no moves
+    fn is_right_terminal(&self) -> bool {
+        self.right_moves().is_empty()
+    }
+
+    /// Check if neither player can move
+    fn is_terminal(&self) -> bool {
+        self.is_left_terminal() && self.is_right_terminal()
+    }
 
     /// Split game into disjoint sum
     ///
@@ -40,29 +296,49 @@ pub trait PartizanGame: Sized + Clone + Hash + Send + Sync + Eq {
     ///
     /// See: zubzero-thermography
     fn thermograph_direct(&self) -> Thermograph {
-        let left_moves = self.left_moves();
-        let right_moves = self.right_moves();
-        if left_moves.is_empty() && right_moves.is_empty() {
-            return Thermograph::with_mast(Rational::from(0));
-        }
+        thermograph_direct_memoized(self, &mut HashMap::new())
+    }
 
-        let mut left_scaffold = Trajectory::new_constant(Rational::NegativeInfinity);
-        let mut right_scaffold = Trajectory::new_constant(Rational::PositiveInfinity);
+    /// Compute the temperature from [`PartizanGame::thermograph_direct`], without going through
+    /// canonical form
+    fn temperature_direct(&self) -> DyadicRationalNumber {
+        self.thermograph_direct().temperature()
+    }
 
-        for left_move in &left_moves {
-            left_scaffold = left_scaffold.max(&left_move.thermograph_direct().right_wall);
-        }
-        for right_move in &right_moves {
-            right_scaffold = right_scaffold.min(&right_move.thermograph_direct().left_wall);
-        }
+    /// Count distinct positions in the game tree, including the position itself.
+    /// Returns [None] if there are more than `max_nodes` positions.
+    fn game_tree_size(&self, max_nodes: usize) -> Option<usize> {
+        let mut seen = HashSet::new();
+        let mut to_visit = vec![self.clone()];
 
-        left_scaffold.tilt(Rational::from(-1));
-        right_scaffold.tilt(Rational::from(1));
+        while let Some(position) = to_visit.pop() {
+            if seen.contains(&position) {
+                continue;
+            }
 
-        Thermograph::thermographic_intersection(left_scaffold, right_scaffold)
+            for m in position
+                .left_moves()
+                .into_iter()
+                .chain(position.right_moves())
+            {
+                if !seen.contains(&m) {
+                    to_visit.push(m);
+                }
+            }
+
+            seen.insert(position);
+            if seen.len() > max_nodes {
+                return None;
+            }
+        }
+
+        Some(seen.len())
     }
 
     /// Handle special cases when computing canonical form doesn't have to compute all moves.
+    ///
+    /// It is consulted for every position reached during [`PartizanGame::canonical_form`],
+    /// including decomposition components.
     fn reductions(&self) -> Option<CanonicalForm> {
         None
     }
@@ -86,42 +362,165 @@ pub trait PartizanGame: Sized + Clone + Hash + Send + Sync + Eq {
         let decompositions = self.decompositions().into_iter();
 
         let sub_results = decompositions.map(|position| {
-            transposition_table.lookup_position(&position).map_or_else(
-                || {
-                    #[cfg(feature = "rayon")]
-                    let left = position.left_moves().into_par_iter();
-                    #[cfg(not(feature = "rayon"))]
-                    let left = position.left_moves().into_iter();
-
-                    #[cfg(feature = "rayon")]
-                    let right = position.right_moves().into_par_iter();
-                    #[cfg(not(feature = "rayon"))]
-                    let right = position.right_moves().into_iter();
-
-                    let moves = Moves {
-                        left: left
-                            .map(|o| o.canonical_form(transposition_table))
-                            .collect(),
-                        right: right
-                            .map(|o| o.canonical_form(transposition_table))
-                            .collect(),
-                    };
-
-                    CanonicalForm::new_from_moves(moves)
-                },
-                |cached_sub_result| cached_sub_result,
-            )
+            transposition_table
+                .lookup_position(&position)
+                .or_else(|| position.reductions())
+                .map_or_else(
+                    || {
+                        let left_moves = position.left_moves();
+                        let right_moves = position.right_moves();
+
+                        #[cfg(feature = "rayon")]
+                        let left = left_moves.par_iter();
+                        #[cfg(not(feature = "rayon"))]
+                        let left = left_moves.iter();
+
+                        #[cfg(feature = "rayon")]
+                        let right = right_moves.par_iter();
+                        #[cfg(not(feature = "rayon"))]
+                        let right = right_moves.iter();
+
+                        let moves = Moves {
+                            left: left
+                                .map(|o| o.canonical_form(transposition_table))
+                                .collect(),
+                            right: right
+                                .map(|o| o.canonical_form(transposition_table))
+                                .collect(),
+                        };
+
+                        CanonicalForm::new_from_moves(moves)
+                    },
+                    |cached_sub_result| cached_sub_result,
+                )
         });
 
         #[cfg(feature = "rayon")]
         let result = sub_results.reduce(|| CanonicalForm::new_integer(0), |a, b| a + b);
         #[cfg(not(feature = "rayon"))]
-        let result = sub_results.fold(CanonicalForm::new_integer(0), |a, b| a + b);
+        let result = sub_results.sum::<CanonicalForm>();
 
         transposition_table.insert_position(self.clone(), result.clone());
         result
     }
 
+    /// Get the canonical form of the game position using a fresh transposition table, see
+    /// [`PartizanGame::canonical_form`]. Convenient for one-off computations, values of many
+    /// related positions are faster to compute with a shared table.
+    fn value(&self) -> CanonicalForm {
+        self.canonical_form(&ParallelTranspositionTable::new())
+    }
+
+    /// Get the canonical form of the game position together with values of all subpositions
+    /// reached during the computation, including decomposition components and the position itself.
+    ///
+    /// Every subposition is expanded once, in order from leaves to the root, so the trace is
+    /// complete even if values are already stored in the transposition table. Computation is
+    /// not parallel.
+    fn canonical_form_with_trace<TT>(
+        &self,
+        transposition_table: &TT,
+    ) -> (CanonicalForm, Vec<(Self, CanonicalForm)>)
+    where
+        TT: TranspositionTable<Self> + Sync,
+    {
+        let mut seen = HashMap::new();
+        let mut trace = Vec::new();
+        let result = canonical_form_traced(self, transposition_table, &mut seen, &mut trace);
+        (result, trace)
+    }
+
+    /// Get the winner with perfect play for each player moving first
+    fn who_wins<TT>(&self, transposition_table: &TT) -> Outcome
+    where
+        TT: TranspositionTable<Self> + Sync,
+    {
+        Outcome::of(&self.canonical_form(transposition_table))
+    }
+
+    /// Check if the position is equal to its negative, e.g. `0`, `*`, or `±1`.
+    ///
+    /// Default implementation compares the canonical form with its negative. Games with a
+    /// player-swap symmetry override it with a cheaper structural check where possible.
+    fn is_symmetric<TT>(&self, transposition_table: &TT) -> bool
+    where
+        TT: TranspositionTable<Self> + Sync,
+    {
+        let value = self.canonical_form(transposition_table);
+        value == -&value
+    }
+
+    /// Approximate canonical form, considering at most `max_moves_per_side` moves for each player
+    /// in every position of the game tree.
+    ///
+    /// The result is **not** the value of the game in general, it is an estimate useful e.g. for
+    /// temperature when computing the exact value is infeasible. It is exact when no position in
+    /// the game tree has more than `max_moves_per_side` moves for either player.
+    ///
+    /// Exact incentives require the values that are being approximated, so moves are ranked by
+    /// an estimate instead: the difference between number of Left and Right moves in the
+    /// resulting position (see [`PartizanGame::num_left_moves`]). Left keeps moves with the
+    /// highest estimate and Right with the lowest. Exact values from `transposition_table` and
+    /// [`PartizanGame::reductions`] are used whenever available, approximations are never
+    /// inserted into the table.
+    fn canonical_form_approx<TT>(
+        &self,
+        transposition_table: &TT,
+        max_moves_per_side: usize,
+    ) -> CanonicalForm
+    where
+        TT: TranspositionTable<Self> + Sync,
+    {
+        canonical_form_approx_memoized(
+            self,
+            transposition_table,
+            max_moves_per_side,
+            &mut HashMap::new(),
+        )
+    }
+
+    /// List of moves for the Left player with their values, best candidates first. Moves are
+    /// ordered by the mean of Left incentive `G^L - G` descending, and moves with equal mean by
+    /// the temperature of the incentive descending, so hotter moves are tried first.
+    fn left_moves_ordered<TT>(&self, transposition_table: &TT) -> Vec<(Self, CanonicalForm)>
+    where
+        TT: TranspositionTable<Self> + Sync,
+    {
+        let value = self.canonical_form(transposition_table);
+        let mut moves = self
+            .left_moves()
+            .into_iter()
+            .map(|m| {
+                let move_value = m.canonical_form(transposition_table);
+                let incentive = &move_value - &value;
+                (incentive.mean(), incentive.temperature(), m, move_value)
+            })
+            .collect::<Vec<_>>();
+        moves.sort_by(|lhs, rhs| rhs.0.cmp(&lhs.0).then(rhs.1.cmp(&lhs.1)));
+        moves.into_iter().map(|(_, _, m, v)| (m, v)).collect()
+    }
+
+    /// List of moves for the Right player with their values, best candidates first.
+    ///
+    /// See [`PartizanGame::left_moves_ordered`], but the Right incentive is `G - G^R`.
+    fn right_moves_ordered<TT>(&self, transposition_table: &TT) -> Vec<(Self, CanonicalForm)>
+    where
+        TT: TranspositionTable<Self> + Sync,
+    {
+        let value = self.canonical_form(transposition_table);
+        let mut moves = self
+            .right_moves()
+            .into_iter()
+            .map(|m| {
+                let move_value = m.canonical_form(transposition_table);
+                let incentive = &value - &move_value;
+                (incentive.mean(), incentive.temperature(), m, move_value)
+            })
+            .collect::<Vec<_>>();
+        moves.sort_by(|lhs, rhs| rhs.0.cmp(&lhs.0).then(rhs.1.cmp(&lhs.1)));
+        moves.into_iter().map(|(_, _, m, v)| (m, v)).collect()
+    }
+
     // TODO: Find a way to reduce duplication - maybe macro?
 
     /// List of canonical moves for the Left player
@@ -160,3 +559,186 @@ pub trait PartizanGame: Sized + Clone + Hash + Send + Sync + Eq {
             .collect::<Vec<_>>()
     }
 }
+
+/// Game played on a grid with finitely many positions of a given size
+pub trait GridEnumerable: Sized {
+    /// List all positions on a `width` by `height` grid
+    fn positions_of_size(width: u8, height: u8) -> Vec<Self>;
+}
+
+fn dedup_symmetry<G>(moves: &[G]) -> Vec<G>
+where
+    G: Symmetry,
+{
+    let mut moves = moves
+        .iter()
+        .map(Symmetry::canonical_symmetry)
+        .collect::<Vec<_>>();
+    moves.sort_unstable();
+    moves.dedup();
+    moves
+}
+
+/// Worker of [`PartizanGame::canonical_form_approx`], shares approximations of positions reachable
+/// in multiple ways
+fn canonical_form_approx_memoized<G, TT>(
+    position: &G,
+    transposition_table: &TT,
+    max_moves_per_side: usize,
+    seen: &mut HashMap<G, CanonicalForm>,
+) -> CanonicalForm
+where
+    G: PartizanGame,
+    TT: TranspositionTable<G> + Sync,
+{
+    if let Some(known) = transposition_table
+        .lookup_position(position)
+        .or_else(|| seen.get(position).cloned())
+        .or_else(|| position.reductions())
+    {
+        return known;
+    }
+
+    let mobility = |m: &G| m.num_left_moves() as i64 - m.num_right_moves() as i64;
+
+    let mut left_moves = position.left_moves();
+    left_moves.sort_by_cached_key(|m| std::cmp::Reverse(mobility(m)));
+    left_moves.truncate(max_moves_per_side);
+
+    let mut right_moves = position.right_moves();
+    right_moves.sort_by_cached_key(mobility);
+    right_moves.truncate(max_moves_per_side);
+
+    let moves = Moves {
+        left: left_moves
+            .iter()
+            .map(|m| {
+                canonical_form_approx_memoized(m, transposition_table, max_moves_per_side, seen)
+            })
+            .collect(),
+        right: right_moves
+            .iter()
+            .map(|m| {
+                canonical_form_approx_memoized(m, transposition_table, max_moves_per_side, seen)
+            })
+            .collect(),
+    };
+    let canonical_form = CanonicalForm::new_from_moves(moves);
+    seen.insert(position.clone(), canonical_form.clone());
+    canonical_form
+}
+
+/// Worker of [`PartizanGame::thermograph_direct`], shares thermographs of positions reachable in
+/// multiple ways
+fn thermograph_direct_memoized<G>(position: &G, seen: &mut HashMap<G, Thermograph>) -> Thermograph
+where
+    G: PartizanGame,
+{
+    if let Some(known) = seen.get(position) {
+        return known.clone();
+    }
+
+    let left_moves = position.left_moves();
+    let right_moves = position.right_moves();
+    let thermograph = if left_moves.is_empty() && right_moves.is_empty() {
+        Thermograph::with_mast(Rational::from(0))
+    } else {
+        let mut left_scaffold = Trajectory::new_constant(Rational::NegativeInfinity);
+        let mut right_scaffold = Trajectory::new_constant(Rational::PositiveInfinity);
+
+        for left_move in &left_moves {
+            left_scaffold =
+                left_scaffold.max(&thermograph_direct_memoized(left_move, seen).right_wall);
+        }
+        for right_move in &right_moves {
+            right_scaffold =
+                right_scaffold.min(&thermograph_direct_memoized(right_move, seen).left_wall);
+        }
+
+        left_scaffold.tilt(Rational::from(-1));
+        right_scaffold.tilt(Rational::from(1));
+
+        Thermograph::thermographic_intersection(left_scaffold, right_scaffold)
+    };
+
+    seen.insert(position.clone(), thermograph.clone());
+    thermograph
+}
+
+/// Worker of [`PartizanGame::canonical_form_with_trace`]
+fn canonical_form_traced<G, TT>(
+    position: &G,
+    transposition_table: &TT,
+    seen: &mut HashMap<G, CanonicalForm>,
+    trace: &mut Vec<(G, CanonicalForm)>,
+) -> CanonicalForm
+where
+    G: PartizanGame,
+    TT: TranspositionTable<G> + Sync,
+{
+    if let Some(known) = seen.get(position) {
+        return known.clone();
+    }
+
+    let result = if let Some(cf) = position.reductions() {
+        cf
+    } else {
+        let decompositions = position.decompositions();
+        let single_component = decompositions.len() == 1;
+        let mut result = CanonicalForm::new_integer(0);
+        for component in decompositions {
+            let component_value = if single_component || !seen.contains_key(&component) {
+                let moves = Moves {
+                    left: component
+                        .left_moves()
+                        .iter()
+                        .map(|m| canonical_form_traced(m, transposition_table, seen, trace))
+                        .collect(),
+                    right: component
+                        .right_moves()
+                        .iter()
+                        .map(|m| canonical_form_traced(m, transposition_table, seen, trace))
+                        .collect(),
+                };
+                let component_value = CanonicalForm::new_from_moves(moves);
+                if !single_component {
+                    seen.insert(component.clone(), component_value.clone());
+                    trace.push((component, component_value.clone()));
+                }
+                component_value
+            } else {
+                seen[&component].clone()
+            };
+            result += component_value;
+        }
+        transposition_table.insert_position(position.clone(), result.clone());
+        result
+    };
+
+    seen.insert(position.clone(), result.clone());
+    trace.push((position.clone(), result.clone()));
+    result
+}
+
+/// Skip positions with temperature at or below `threshold`, the same way search commands do.
+///
+/// Use [`Rational::NegativeInfinity`] to keep all positions, including ones with temperature `-1`.
+pub fn filter_by_temperature<'a, G, I, TT>(
+    positions: I,
+    threshold: Rational,
+    transposition_table: &'a TT,
+) -> impl Iterator<Item = G> + 'a
+where
+    G: PartizanGame + 'a,
+    I: IntoIterator<Item = G>,
+    I::IntoIter: 'a,
+    TT: TranspositionTable<G> + Sync,
+{
+    positions.into_iter().filter(move |position| {
+        position
+            .canonical_form(transposition_table)
+            .temperature()
+            .to_rational()
+            > threshold
+    })
+}
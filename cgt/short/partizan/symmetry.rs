@@ -0,0 +1,21 @@
+//! Symmetries of game positions that preserve the game value
+
+/// Position with a group of symmetries that do not change its game value
+pub trait Symmetry: Sized + Ord {
+    /// Images of the position under every symmetry in the game's symmetry group, including the
+    /// position itself. Symmetries must form a group, otherwise [`Symmetry::canonical_symmetry`]
+    /// is not stable across the images.
+    fn symmetries(&self) -> Vec<Self>;
+
+    /// Representative of the symmetry class. All images from [`Symmetry::symmetries`] have the
+    /// same representative, so it can be used as a cache key.
+    ///
+    /// # Panics
+    /// - [`Symmetry::symmetries`] returned no positions
+    fn canonical_symmetry(&self) -> Self {
+        self.symmetries()
+            .into_iter()
+            .min()
+            .expect("symmetry group must contain identity")
+    }
+}
@@ -77,6 +77,23 @@ impl Thermograph {
         }
     }
 
+    /// Get the point `(temperature, value)` where left and right walls merge into the mast
+    pub fn mast_point(&self) -> (Rational, Rational) {
+        (self.temperature().to_rational(), self.get_mast())
+    }
+
+    /// Pointwise difference of left and right walls. Useful for checking at which temperatures one
+    /// position is hotter than the other.
+    ///
+    /// # Panics
+    /// - When any of the walls is infinite
+    pub fn difference(&self, other: &Self) -> Self {
+        Self {
+            left_wall: self.left_wall.difference(&other.left_wall),
+            right_wall: self.right_wall.difference(&other.right_wall),
+        }
+    }
+
     /// Calculate a thermograph given left and right scaffold. Note that scaffolds should be
     /// [tilted](Trajectory::tilt) before.
     #[cfg_attr(
@@ -621,3 +638,48 @@ impl Display for Thermograph {
         display::parens(f, |f| write!(f, "{}, {}", self.left_wall, self.right_wall))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::short::partizan::canonical_form::CanonicalForm;
+    use std::str::FromStr;
+
+    #[test]
+    fn mast_point_works() {
+        let thermograph = CanonicalForm::from_str("{2|-2}").unwrap().thermograph();
+        assert_eq!(
+            thermograph.mast_point(),
+            (Rational::from(2), Rational::from(0))
+        );
+    }
+
+    #[test]
+    fn difference_with_self_is_zero() {
+        for cf in ["{2|-2}", "{3|{1|0}}", "{{5|2}|-1}", "1/2"] {
+            let thermograph = CanonicalForm::from_str(cf).unwrap().thermograph();
+            assert_eq!(
+                thermograph.difference(&thermograph),
+                Thermograph::with_mast(Rational::from(0))
+            );
+        }
+    }
+
+    #[test]
+    fn difference_works() {
+        let lhs = CanonicalForm::from_str("{2|-2}").unwrap().thermograph();
+        let rhs = CanonicalForm::from_str("{1|-1}").unwrap().thermograph();
+        let difference = lhs.difference(&rhs);
+        for t in [-1, 0, 1, 2, 3] {
+            let t = Rational::from(t);
+            assert_eq!(
+                difference.left_wall.value_at(t),
+                lhs.left_wall.value_at(t) - rhs.left_wall.value_at(t)
+            );
+            assert_eq!(
+                difference.right_wall.value_at(t),
+                lhs.right_wall.value_at(t) - rhs.right_wall.value_at(t)
+            );
+        }
+    }
+}
@@ -162,6 +162,62 @@ impl Trajectory {
         }
     }
 
+    /// Pointwise difference of two finite trajectories
+    pub(crate) fn difference(&self, other: &Self) -> Self {
+        let mut all_critical_points = self
+            .critical_points
+            .iter()
+            .chain(other.critical_points.iter())
+            .copied()
+            .collect::<Vec<_>>();
+        all_critical_points.sort_unstable_by(|lhs, rhs| rhs.cmp(lhs));
+        all_critical_points.dedup();
+
+        let mut critical_points = Vec::with_capacity(all_critical_points.len());
+        let mut slopes = Vec::with_capacity(all_critical_points.len() + 1);
+        let mut x_intercepts = Vec::with_capacity(all_critical_points.len() + 1);
+
+        for segment in 0..=all_critical_points.len() {
+            // Index of the segment in both trajectories that lies right below the upper bound
+            let (self_segment, other_segment) = if segment == 0 {
+                (0, 0)
+            } else {
+                let upper = all_critical_points[segment - 1];
+                (
+                    self.critical_points
+                        .iter()
+                        .take_while(|cp| **cp >= upper)
+                        .count(),
+                    other
+                        .critical_points
+                        .iter()
+                        .take_while(|cp| **cp >= upper)
+                        .count(),
+                )
+            };
+
+            let slope = self.slopes[self_segment] - other.slopes[other_segment];
+            let x_intercept = self.x_intercepts[self_segment] - other.x_intercepts[other_segment];
+
+            // Same line continues, so the critical point is not needed
+            if slopes.last() == Some(&slope) && x_intercepts.last() == Some(&x_intercept) {
+                continue;
+            }
+
+            if segment != 0 {
+                critical_points.push(all_critical_points[segment - 1]);
+            }
+            slopes.push(slope);
+            x_intercepts.push(x_intercept);
+        }
+
+        Self {
+            critical_points,
+            slopes,
+            x_intercepts,
+        }
+    }
+
     #[inline]
     fn is_infinite(&self) -> bool {
         self.x_intercepts[0].is_infinite()
@@ -1,9 +1,30 @@
 //! Thread safe transposition table for game values
 
-use crate::short::partizan::canonical_form::CanonicalForm;
+use crate::short::partizan::{canonical_form::CanonicalForm, symmetry::Symmetry};
 use append_only_vec::AppendOnlyVec;
 use dashmap::DashMap;
-use std::{hash::Hash, marker::PhantomData};
+use std::{
+    hash::Hash,
+    marker::PhantomData,
+    sync::atomic::{self, AtomicUsize},
+};
+
+/// Usage statistics of a transposition table
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CacheStats {
+    /// Number of lookups that found a position
+    pub hits: usize,
+
+    /// Number of lookups that did not find a position
+    pub misses: usize,
+
+    /// Number of distinct game values stored
+    pub known_games: usize,
+
+    /// Number of positions stored
+    pub known_grids: usize,
+}
 
 /// Interface of a transposition table
 pub trait TranspositionTable<G> {
@@ -12,35 +33,80 @@ pub trait TranspositionTable<G> {
 
     /// Save position and its game value
     fn insert_position(&self, position: G, value: CanonicalForm);
+
+    /// Get usage statistics. Tables that do not track them report zeros.
+    fn stats(&self) -> CacheStats {
+        CacheStats::default()
+    }
+
+    /// Get number of saved positions
+    fn len(&self) -> usize {
+        self.stats().known_grids
+    }
+
+    /// Check if table stores any position
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Check if table reached its position limit and will drop positions on next insert
+    fn is_full(&self) -> bool {
+        false
+    }
+
+    /// Drop all saved positions, but keep known game values
+    fn clear_grids(&self) {}
 }
 
+/// Number of positions [`ParallelTranspositionTable::new`] preallocates space for
+pub const DEFAULT_CAPACITY: usize = 1 << 12;
+
 /// Transaction table (cache) of game positions and canonical forms.
 pub struct ParallelTranspositionTable<G> {
     values: AppendOnlyVec<CanonicalForm>,
     positions: DashMap<G, usize, ahash::RandomState>,
     known_values: DashMap<CanonicalForm, usize, ahash::RandomState>,
+    hits: AtomicUsize,
+    misses: AtomicUsize,
+    max_positions: Option<usize>,
 }
 
 impl<G> ParallelTranspositionTable<G>
 where
     G: Eq + Hash,
 {
-    /// Create new empty transposition table.
+    /// Create new empty transposition table with space for [`DEFAULT_CAPACITY`] positions.
     #[inline]
     pub fn new() -> Self {
         Self::default()
     }
 
-    /// Get number of saved positions
+    /// Create new empty transposition table with space preallocated for `capacity` positions.
+    ///
+    /// Every slot holds a position and an index of its game value, so roughly
+    /// `capacity * (size_of::<G>() + size_of::<usize>())` bytes are allocated upfront. Capacity
+    /// is not a limit, the table grows when it is exceeded, see
+    /// [`ParallelTranspositionTable::with_max_positions`] to bound memory usage instead.
     #[inline]
-    pub fn len(&self) -> usize {
-        self.positions.len()
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            values: AppendOnlyVec::new(),
+            positions: DashMap::with_capacity_and_hasher(capacity, ahash::RandomState::default()),
+            known_values: DashMap::default(),
+            hits: AtomicUsize::new(0),
+            misses: AtomicUsize::new(0),
+            max_positions: None,
+        }
     }
 
-    /// Check if table stores any position
+    /// Create new empty transposition table that drops all saved positions when it reaches
+    /// `max_positions` positions, to bound memory usage. Known game values are kept.
     #[inline]
-    pub fn is_empty(&self) -> bool {
-        self.positions.is_empty()
+    pub fn with_max_positions(max_positions: usize) -> Self {
+        Self {
+            max_positions: Some(max_positions),
+            ..Self::default()
+        }
     }
 }
 
@@ -50,11 +116,7 @@ where
 {
     #[inline]
     fn default() -> Self {
-        Self {
-            values: AppendOnlyVec::new(),
-            positions: DashMap::default(),
-            known_values: DashMap::default(),
-        }
+        Self::with_capacity(DEFAULT_CAPACITY)
     }
 }
 
@@ -65,22 +127,137 @@ where
     #[cfg_attr(feature = "cargo-clippy", allow(clippy::missing_panics_doc))]
     #[inline]
     fn lookup_position(&self, position: &G) -> Option<CanonicalForm> {
-        self.positions
+        let result = self
+            .positions
             .get(position)
-            .map(|id| self.values[*id].clone())
+            .map(|id| self.values[*id].clone());
+        let counter = if result.is_some() {
+            &self.hits
+        } else {
+            &self.misses
+        };
+        counter.fetch_add(1, atomic::Ordering::Relaxed);
+        result
     }
 
     #[cfg_attr(feature = "cargo-clippy", allow(clippy::missing_panics_doc))]
     #[inline]
     fn insert_position(&self, position: G, value: CanonicalForm) {
-        if let Some(known) = self.known_values.get(&value) {
-            self.positions.insert(position, *known);
-        } else {
-            let inserted = self.values.push(value.clone());
-            self.known_values.insert(value, inserted);
-            self.positions.insert(position, inserted);
+        if self.is_full() {
+            self.clear_grids();
+        }
+
+        // Values are keyed by their structural hash, so equal games are stored once. Entry
+        // holds the shard lock, so concurrent inserts of the same new value cannot store it twice.
+        let known = self.known_values.get(&value).map(|known| *known);
+        let id = known.unwrap_or_else(|| {
+            *self
+                .known_values
+                .entry(value.clone())
+                .or_insert_with(|| self.values.push(value))
+        });
+        self.positions.insert(position, id);
+    }
+
+    #[inline]
+    fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(atomic::Ordering::Relaxed),
+            misses: self.misses.load(atomic::Ordering::Relaxed),
+            known_games: self.values.len(),
+            known_grids: self.positions.len(),
         }
     }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    #[inline]
+    fn is_full(&self) -> bool {
+        self.max_positions
+            .is_some_and(|max_positions| self.positions.len() >= max_positions)
+    }
+
+    #[inline]
+    fn clear_grids(&self) {
+        self.positions.clear();
+    }
+}
+
+/// Transposition table that keys positions on their [`Symmetry::canonical_symmetry`], so all
+/// symmetric images of a position share a single entry.
+pub struct SymmetricTranspositionTable<G> {
+    inner: ParallelTranspositionTable<G>,
+}
+
+impl<G> SymmetricTranspositionTable<G>
+where
+    G: Eq + Hash,
+{
+    /// Create new empty transposition table.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create new empty transposition table with space preallocated for `capacity` symmetry
+    /// classes, see [`ParallelTranspositionTable::with_capacity`]
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: ParallelTranspositionTable::with_capacity(capacity),
+        }
+    }
+}
+
+impl<G> Default for SymmetricTranspositionTable<G>
+where
+    G: Hash + Eq,
+{
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: ParallelTranspositionTable::default(),
+        }
+    }
+}
+
+impl<G> TranspositionTable<G> for SymmetricTranspositionTable<G>
+where
+    G: Eq + Hash + Symmetry,
+{
+    #[inline]
+    fn lookup_position(&self, position: &G) -> Option<CanonicalForm> {
+        self.inner.lookup_position(&position.canonical_symmetry())
+    }
+
+    #[inline]
+    fn insert_position(&self, position: G, value: CanonicalForm) {
+        self.inner
+            .insert_position(position.canonical_symmetry(), value);
+    }
+
+    #[inline]
+    fn stats(&self) -> CacheStats {
+        self.inner.stats()
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    #[inline]
+    fn is_full(&self) -> bool {
+        self.inner.is_full()
+    }
+
+    #[inline]
+    fn clear_grids(&self) {
+        self.inner.clear_grids();
+    }
 }
 
 /// Dummy transposition table that does not store anythning
@@ -110,3 +287,52 @@ impl<G> TranspositionTable<G> for NoTranspositionTable<G> {
     #[inline]
     fn insert_position(&self, _position: G, _value: CanonicalForm) {}
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        grid::small_bit_grid::SmallBitGrid,
+        short::partizan::{
+            canonical_form::Moves, games::domineering::Domineering, partizan_game::PartizanGame,
+        },
+    };
+
+    #[test]
+    fn equal_games_are_stored_once() {
+        let transposition_table = ParallelTranspositionTable::new();
+        let construct = || {
+            CanonicalForm::new_from_moves(
+                Moves::builder()
+                    .left(CanonicalForm::new_integer(1))
+                    .right(CanonicalForm::new_integer(-1))
+                    .build(),
+            )
+        };
+
+        transposition_table.insert_position(0, construct());
+        transposition_table.insert_position(1, construct());
+        transposition_table.insert_position(2, CanonicalForm::new_integer(0));
+
+        let stats = transposition_table.stats();
+        assert_eq!(stats.known_grids, 3);
+        assert_eq!(stats.known_games, 2);
+        assert_eq!(transposition_table.lookup_position(&1), Some(construct()));
+    }
+
+    #[test]
+    fn tiny_capacity_grows() {
+        let tiny = ParallelTranspositionTable::with_capacity(1);
+        let default = ParallelTranspositionTable::new();
+        for id in 0..(1 << 9) {
+            let position = Domineering::new(SmallBitGrid::from_number(3, 3, id).unwrap());
+            assert_eq!(
+                position.canonical_form(&tiny),
+                position.canonical_form(&default),
+                "{position}"
+            );
+        }
+        assert_eq!(tiny.len(), default.len());
+        assert!(tiny.len() > 1);
+    }
+}
@@ -1,7 +1,8 @@
 use anyhow::{Context, Result};
 use cgt::short::partizan::{
-    games::amazons::Amazons, partizan_game::PartizanGame,
-    transposition_table::ParallelTranspositionTable,
+    games::amazons::Amazons,
+    partizan_game::PartizanGame,
+    transposition_table::{ParallelTranspositionTable, DEFAULT_CAPACITY},
 };
 use clap::{self, Parser};
 use std::str::FromStr;
@@ -12,6 +13,10 @@ pub struct Args {
     /// Amazons position to evalueate (e.g. '.x.|o#.|..#')
     #[arg(long)]
     position: String,
+
+    /// Number of positions to preallocate space for in the transposition table
+    #[arg(long, default_value_t = DEFAULT_CAPACITY)]
+    transposition_capacity: usize,
 }
 
 pub fn run(args: Args) -> Result<()> {
@@ -20,7 +25,7 @@ pub fn run(args: Args) -> Result<()> {
         .context("Could not parse the position")?;
     eprintln!("Game: {}", pos);
 
-    let tt = ParallelTranspositionTable::new();
+    let tt = ParallelTranspositionTable::with_capacity(args.transposition_capacity);
     let cf = pos.canonical_form(&tt);
     eprintln!("Canonical Form: {}", cf);
     eprintln!("Temperature: {}", cf.temperature());
@@ -5,4 +5,6 @@ crate::clap_utils::mk_subcommand! {
     GeneticSearch => genetic_search,
     Evaluate => evaluate,
     LatexTable => latex_table,
+    Value => value,
+    Spectrum => spectrum,
 }
@@ -1,7 +1,185 @@
+use anyhow::{bail, Result};
+use cgt::{
+    grid::FiniteGrid,
+    short::partizan::{canonical_form::CanonicalForm, games::domineering::Domineering},
+};
+use clap::ValueEnum;
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug)]
+/// Current version of [`DomineeringResult`] format
+pub const DOMINEERING_RESULT_VERSION: u32 = 1;
+
+/// Version of results written before the format was versioned, which have no `version` field
+const UNVERSIONED_RESULT_VERSION: u32 = 0;
+
+/// Header of CSV output, see [`DomineeringResult::to_csv_row`]
+pub const CSV_HEADER: &str = "width,height,grid,temperature,canonical_form,mean";
+
+/// Format of search results
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum OutputFormat {
+    /// Newline separated JSON objects
+    Json,
+
+    /// Comma separated values with a header row
+    Csv,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
 pub struct DomineeringResult {
+    /// Missing in results written before the format was versioned, see
+    /// [`DomineeringResult::check_version`]
+    #[serde(default)]
+    pub version: u32,
     pub grid: String,
+    pub canonical_form: String,
     pub temperature: String,
 }
+
+impl DomineeringResult {
+    pub fn new(grid: String, canonical_form: String, temperature: String) -> Self {
+        Self {
+            version: DOMINEERING_RESULT_VERSION,
+            grid,
+            canonical_form,
+            temperature,
+        }
+    }
+
+    /// Fail if result was produced with unsupported format version
+    pub fn check_version(&self) -> Result<()> {
+        if self.version == UNVERSIONED_RESULT_VERSION {
+            bail!(
+                "Result has no version, it was produced by an older cgt-cli. Regenerate it to get \
+                 version {}",
+                DOMINEERING_RESULT_VERSION
+            );
+        }
+        if self.version != DOMINEERING_RESULT_VERSION {
+            bail!(
+                "Unsupported result version {}, expected {}",
+                self.version,
+                DOMINEERING_RESULT_VERSION
+            );
+        }
+        Ok(())
+    }
+
+    /// Render result as a CSV row with columns from [`CSV_HEADER`]. Derived columns are taken from
+    /// `position` and `canonical_form` the result was created from, to avoid parsing them again.
+    pub fn to_csv_row(&self, position: &Domineering, canonical_form: &CanonicalForm) -> String {
+        [
+            position.grid().width().to_string(),
+            position.grid().height().to_string(),
+            csv_escape(&self.grid),
+            csv_escape(&self.temperature),
+            csv_escape(&self.canonical_form),
+            csv_escape(&canonical_form.mean().to_string()),
+        ]
+        .join(",")
+    }
+
+    /// Read result from a CSV row produced by [`DomineeringResult::to_csv_row`]. Derived columns
+    /// are ignored.
+    pub fn from_csv_row(row: &str) -> Result<Self> {
+        let fields = csv_split(row)?;
+        if fields.len() != 6 {
+            bail!("Expected 6 columns, got {}", fields.len());
+        }
+        Ok(Self::new(
+            fields[2].clone(),
+            fields[4].clone(),
+            fields[3].clone(),
+        ))
+    }
+}
+
+/// Quote field if it contains characters that could confuse spreadsheet software
+fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '{', '}', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Split CSV row into fields, unquoting them
+fn csv_split(row: &str) -> Result<Vec<String>> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = row.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    if quoted {
+        bail!("Unterminated quoted field");
+    }
+    fields.push(field);
+    Ok(fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn deserialize_roundtrip() {
+        let line = r#"{"version":1,"grid":"..|..","canonical_form":"{1|-1}","temperature":"1"}"#;
+        let result: DomineeringResult = serde_json::from_str(line).unwrap();
+        result.check_version().unwrap();
+        assert_eq!(
+            result,
+            DomineeringResult::new("..|..".into(), "{1|-1}".into(), "1".into())
+        );
+        assert_eq!(serde_json::to_string(&result).unwrap(), line);
+    }
+
+    #[test]
+    fn rejects_unknown_version() {
+        let line = r#"{"version":42,"grid":"..|..","canonical_form":"{1|-1}","temperature":"1"}"#;
+        let result: DomineeringResult = serde_json::from_str(line).unwrap();
+        assert!(result.check_version().is_err());
+    }
+
+    #[test]
+    fn rejects_unversioned() {
+        let line = r#"{"grid":"..|..","canonical_form":"{1|-1}","temperature":"1"}"#;
+        let result: DomineeringResult = serde_json::from_str(line).unwrap();
+        let err = result.check_version().unwrap_err();
+        assert!(err.to_string().contains("no version"), "{err}");
+    }
+
+    #[test]
+    fn csv_roundtrip() {
+        let results = [
+            DomineeringResult::new("..|..".into(), "{1|-1}".into(), "1".into()),
+            DomineeringResult::new(".#.|...".into(), "{1/2,{1|0}|-1}".into(), "3/4".into()),
+        ];
+
+        let mut csv = String::from(CSV_HEADER);
+        for result in &results {
+            let position = Domineering::from_str(&result.grid).unwrap();
+            let canonical_form = CanonicalForm::from_str(&result.canonical_form).unwrap();
+            csv.push('\n');
+            csv.push_str(&result.to_csv_row(&position, &canonical_form));
+        }
+        assert_eq!(csv.lines().nth(1).unwrap(), r#"2,2,..|..,1,"{1|-1}",0"#);
+
+        let read = csv
+            .lines()
+            .skip(1)
+            .map(|row| DomineeringResult::from_csv_row(row).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(read, results);
+    }
+}
@@ -3,8 +3,9 @@ use anyhow::{Context, Result};
 use cgt::{
     drawing::svg::Svg,
     short::partizan::{
-        games::domineering::Domineering, partizan_game::PartizanGame,
-        transposition_table::ParallelTranspositionTable,
+        games::domineering::Domineering,
+        partizan_game::PartizanGame,
+        transposition_table::{ParallelTranspositionTable, DEFAULT_CAPACITY},
     },
 };
 use clap::Parser;
@@ -23,6 +24,10 @@ pub struct Args {
     /// SVG render output path
     #[arg(long, default_value = None)]
     output_svg: Option<FileOrStdout>,
+
+    /// Number of positions to preallocate space for in the transposition table
+    #[arg(long, default_value_t = DEFAULT_CAPACITY)]
+    transposition_capacity: usize,
 }
 
 pub fn run(args: Args) -> Result<()> {
@@ -42,7 +47,7 @@ pub fn run(args: Args) -> Result<()> {
             .context(format!("Could not write to file '{}'", svg_fp))?;
     }
 
-    let tt = ParallelTranspositionTable::new();
+    let tt = ParallelTranspositionTable::with_capacity(args.transposition_capacity);
     let canonical_form = position.canonical_form(&tt);
     println!("Canonical Form: {}", canonical_form);
     println!("Temperature: {}", canonical_form.temperature());
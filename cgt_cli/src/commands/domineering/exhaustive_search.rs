@@ -5,7 +5,7 @@ use cgt::{
     short::partizan::{
         games::domineering,
         partizan_game::PartizanGame,
-        transposition_table::{NoTranspositionTable, ParallelTranspositionTable},
+        transposition_table::{NoTranspositionTable, ParallelTranspositionTable, DEFAULT_CAPACITY},
     },
 };
 use clap::{Parser, ValueEnum};
@@ -17,7 +17,7 @@ use std::{
     thread, time,
 };
 
-use super::common::DomineeringResult;
+use super::common::{DomineeringResult, OutputFormat, CSV_HEADER};
 
 #[derive(Debug, Clone, Copy, ValueEnum)]
 enum ThermographMethod {
@@ -68,6 +68,10 @@ pub struct Args {
     #[arg(long)]
     output_path: String,
 
+    /// Format of saved positions
+    #[arg(long, value_enum, default_value_t = OutputFormat::Json)]
+    format: OutputFormat,
+
     /// Maximum empty tiles to compute
     #[arg(long, default_value = None)]
     max_empty_tiles: Option<usize>,
@@ -79,6 +83,10 @@ pub struct Args {
     /// Don't use transposition table of game positions and canonical forms
     #[arg(long, default_value_t = false)]
     no_transposition_table: bool,
+
+    /// Number of positions to preallocate space for in the transposition table
+    #[arg(long, default_value_t = DEFAULT_CAPACITY)]
+    transposition_capacity: usize,
 }
 
 struct ProgressTracker {
@@ -115,10 +123,22 @@ impl ProgressTracker {
     }
 }
 
-pub fn run(args: Args) -> Result<()> {
-    let grid_tiles = args.width * args.height;
+/// Number of distinct grids of given size, i.e. one past the highest position id
+fn position_count(width: u8, height: u8) -> Result<u64> {
+    let grid_tiles = width as u32 * height as u32;
+    match 1u64.checked_shl(grid_tiles) {
+        Some(count) => Ok(count),
+        None => bail!(
+            "Grid {}x{} has {} tiles, but at most 63 are supported.",
+            width,
+            height,
+            grid_tiles
+        ),
+    }
+}
 
-    let max_last_id: u64 = 1 << grid_tiles;
+pub fn run(args: Args) -> Result<()> {
+    let max_last_id = position_count(args.width, args.height)?;
     let last_id: u64 = match args.last_id {
         None => max_last_id,
         Some(last_id) => last_id,
@@ -135,11 +155,16 @@ pub fn run(args: Args) -> Result<()> {
     let transposition_table = if args.no_transposition_table {
         None
     } else {
-        Some(ParallelTranspositionTable::new())
+        Some(ParallelTranspositionTable::with_capacity(
+            args.transposition_capacity,
+        ))
     };
 
-    let output_file =
+    let mut output_file =
         File::create(&args.output_path).with_context(|| "Could not open output file")?;
+    if let OutputFormat::Csv = args.format {
+        writeln!(output_file, "{CSV_HEADER}").with_context(|| "Could not write output file")?;
+    }
     let progress_tracker = Arc::new(ProgressTracker::new(args, output_file));
 
     let progress_tracker_cpy = progress_tracker.clone();
@@ -188,18 +213,21 @@ pub fn run(args: Args) -> Result<()> {
                 }
             }
 
-            let thermograph = match progress_tracker.args.thermograph_method {
+            let compute_canonical_form = || {
+                if let Some(ref transposition_table) = transposition_table {
+                    grid.canonical_form(transposition_table)
+                } else {
+                    grid.canonical_form(&NoTranspositionTable::new())
+                }
+            };
+
+            let (canonical_form, thermograph) = match progress_tracker.args.thermograph_method {
                 ThermographMethod::CanonicalForm => {
-                    let canonical_form = {
-                        if let Some(ref transposition_table) = transposition_table {
-                            grid.canonical_form(transposition_table)
-                        } else {
-                            grid.canonical_form(&NoTranspositionTable::new())
-                        }
-                    };
-                    canonical_form.thermograph()
+                    let canonical_form = compute_canonical_form();
+                    let thermograph = canonical_form.thermograph();
+                    (Some(canonical_form), thermograph)
                 }
-                ThermographMethod::Direct => grid.thermograph_direct(),
+                ThermographMethod::Direct => (None, grid.thermograph_direct()),
             };
             let temperature = thermograph.temperature();
 
@@ -210,12 +238,19 @@ pub fn run(args: Args) -> Result<()> {
                 }
             }
 
-            // Save results as newline separated JSON objects
-            let result = DomineeringResult {
-                grid: format!("{grid}"),
-                temperature: format!("{temperature}"),
+            // Save results as newline separated JSON objects or CSV rows
+            // Canonical form is computed only for saved positions when using direct method
+            let canonical_form = canonical_form.unwrap_or_else(compute_canonical_form);
+            let result = DomineeringResult::new(
+                format!("{grid}"),
+                format!("{canonical_form}"),
+                format!("{temperature}"),
+            );
+            let to_write = match progress_tracker.args.format {
+                OutputFormat::Json => serde_json::ser::to_string(&result).unwrap(),
+                OutputFormat::Csv => result.to_csv_row(&grid, &canonical_form),
             };
-            let to_write = format!("{}\n", serde_json::ser::to_string(&result).unwrap());
+            let to_write = format!("{to_write}\n");
             progress_tracker.write_game(&to_write);
 
             {
@@ -242,8 +277,9 @@ fn zero_padded(to_pad: u128, max_size: u128) -> String {
 }
 
 fn progress_report(progress_tracker: Arc<ProgressTracker>) {
-    let grid_tiles = progress_tracker.args.width * progress_tracker.args.height;
-    let max_last_id: u64 = 1 << grid_tiles;
+    // NOTE: Size is validated before the search starts
+    let max_last_id = position_count(progress_tracker.args.width, progress_tracker.args.height)
+        .unwrap_or(u64::MAX);
     let last_id: u64 = match progress_tracker.args.last_id {
         None => max_last_id,
         Some(last_id) => last_id,
@@ -300,3 +336,17 @@ fn progress_report(progress_tracker: Arc<ProgressTracker>) {
         ));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn position_count_does_not_wrap() {
+        assert_eq!(position_count(3, 2).unwrap(), 64);
+        assert_eq!(position_count(7, 9).unwrap(), 1 << 63);
+        // 16 * 16 wraps to 0 in u8 arithmetic
+        assert!(position_count(16, 16).is_err());
+        assert!(position_count(8, 8).is_err());
+    }
+}
@@ -5,12 +5,12 @@ use crate::{
 use anyhow::{bail, Context, Result};
 use cgt::{
     genetic_algorithm::{Algorithm, GeneticAlgorithm},
-    grid::{small_bit_grid::SmallBitGrid, BitTile, FiniteGrid, Grid},
+    grid::{small_bit_grid::SmallBitGrid, FiniteGrid, Grid},
     numeric::dyadic_rational_number::DyadicRationalNumber,
     short::partizan::{
         games::domineering::{Domineering, Tile},
         partizan_game::PartizanGame,
-        transposition_table::ParallelTranspositionTable,
+        transposition_table::{ParallelTranspositionTable, DEFAULT_CAPACITY},
     },
 };
 use clap::Parser;
@@ -49,16 +49,20 @@ pub struct Args {
 
     #[arg(long, default_value = None)]
     seed: Option<String>,
+
+    /// Number of positions to preallocate space for in the transposition table
+    #[arg(long, default_value_t = DEFAULT_CAPACITY)]
+    transposition_capacity: usize,
 }
 
-struct DomineeringHighTemperature {
-    transposition_table: ParallelTranspositionTable<Domineering>,
+struct DomineeringHighTemperature<'tt> {
+    transposition_table: &'tt ParallelTranspositionTable<Domineering>,
     mutation_rate: f32,
     grid_width: u8,
     grid_height: u8,
 }
 
-impl Algorithm<Domineering, DyadicRationalNumber> for DomineeringHighTemperature {
+impl Algorithm<Domineering, DyadicRationalNumber> for DomineeringHighTemperature<'_> {
     fn mutate(&self, object: &mut Domineering, rng: &mut rand::rngs::ThreadRng) {
         for y in 0..object.grid().height() {
             for x in 0..object.grid().width() {
@@ -107,31 +111,31 @@ impl Algorithm<Domineering, DyadicRationalNumber> for DomineeringHighTemperature
             self.lowest_score()
         } else {
             object
-                .canonical_form(&self.transposition_table)
+                .canonical_form(self.transposition_table)
                 .temperature()
         }
     }
 
     fn random(&self, rng: &mut rand::rngs::ThreadRng) -> Domineering {
-        let mut new =
-            Domineering::new(SmallBitGrid::empty(self.grid_width, self.grid_height).unwrap());
-
-        for y in 0..new.grid().height() {
-            for x in 0..new.grid().width() {
-                new.grid_mut()
-                    .set(x, y, Tile::bool_to_tile(rng.gen_bool(0.5)));
-            }
-        }
-
-        new
+        Domineering::random(rng, self.grid_width, self.grid_height, 0.5).unwrap()
     }
 }
 
 // "##.#.##|##...##|....#..|#.....#|..##...|##...##|##.#.##"
 
 pub fn run(args: Args) -> Result<()> {
+    if SmallBitGrid::<Tile>::empty(args.width, args.height).is_none() {
+        bail!(
+            "Grid {}x{} is too large, at most 64 tiles are supported",
+            args.width,
+            args.height
+        );
+    }
+
+    let transposition_table =
+        ParallelTranspositionTable::with_capacity(args.transposition_capacity);
     let alg = DomineeringHighTemperature {
-        transposition_table: ParallelTranspositionTable::new(),
+        transposition_table: &transposition_table,
         mutation_rate: args.mutation_rate,
         grid_width: args.width,
         grid_height: args.height,
@@ -185,10 +189,11 @@ pub fn run(args: Args) -> Result<()> {
             .take_while(|s| s.score >= args.temperature_threshold)
             .try_for_each(|s| -> Result<()> {
                 if visited.insert(s.object) {
-                    let result = DomineeringResult {
-                        grid: s.object.to_string(),
-                        temperature: s.score.to_string(),
-                    };
+                    let result = DomineeringResult::new(
+                        s.object.to_string(),
+                        s.object.canonical_form(&transposition_table).to_string(),
+                        s.score.to_string(),
+                    );
                     writeln!(output, "{}", serde_json::ser::to_string(&result).unwrap())
                         .context("Could not output position")?;
                     output.flush().context("Could not flush logs")?;
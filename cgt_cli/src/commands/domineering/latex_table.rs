@@ -1,4 +1,4 @@
-use super::common::DomineeringResult;
+use super::common::{DomineeringResult, CSV_HEADER};
 use anyhow::{anyhow, Context, Result};
 use cgt::{grid::FiniteGrid, numeric::rational::Rational, short::partizan::games::domineering};
 use clap::Parser;
@@ -16,6 +16,7 @@ struct DomineeringEntry {
 
 impl DomineeringEntry {
     fn new(result: &DomineeringResult) -> Result<Self> {
+        result.check_version()?;
         Ok(DomineeringEntry {
             temperature: Rational::from_str(&result.temperature)
                 .ok()
@@ -27,10 +28,10 @@ impl DomineeringEntry {
     }
 }
 
-/// Convert search report (sequence of JSON objects) to LaTeX table
+/// Convert search report (sequence of JSON objects or CSV rows) to LaTeX table
 #[derive(Parser, Debug)]
 pub struct Args {
-    /// Input newline-separated JSON file, usually obtained by running `search` command. Use '-' for stdin
+    /// Input newline-separated JSON or CSV file, usually obtained by running `search` command. Use '-' for stdin
     #[arg(long)]
     in_file: String,
 
@@ -52,7 +53,7 @@ pub struct Args {
 }
 
 pub fn run(args: Args) -> Result<()> {
-    let input: BufReader<Box<dyn Read>> = if args.in_file == "-" {
+    let mut input: BufReader<Box<dyn Read>> = if args.in_file == "-" {
         BufReader::new(Box::new(stdin()))
     } else {
         BufReader::new(Box::new(
@@ -70,13 +71,30 @@ pub fn run(args: Args) -> Result<()> {
         ))?))
     };
 
-    let input = serde_json::de::Deserializer::from_reader(input)
-        .into_iter::<DomineeringResult>()
-        .map(|line| {
-            line.context("Could not parse JSON '{line}'")
-                .and_then(|r| DomineeringEntry::new(&r))
-        })
-        .collect::<Result<Vec<_>, _>>()?;
+    let mut contents = String::new();
+    input
+        .read_to_string(&mut contents)
+        .context("Could not read input")?;
+
+    let input = if contents.lines().next() == Some(CSV_HEADER) {
+        contents
+            .lines()
+            .skip(1)
+            .map(|row| {
+                DomineeringResult::from_csv_row(row)
+                    .with_context(|| format!("Could not parse CSV row '{row}'"))
+                    .and_then(|r| DomineeringEntry::new(&r))
+            })
+            .collect::<Result<Vec<_>, _>>()?
+    } else {
+        serde_json::de::Deserializer::from_str(&contents)
+            .into_iter::<DomineeringResult>()
+            .map(|line| {
+                line.context("Could not parse JSON '{line}'")
+                    .and_then(|r| DomineeringEntry::new(&r))
+            })
+            .collect::<Result<Vec<_>, _>>()?
+    };
 
     // remove rotations
     let mut input = if args.include_rotations {
@@ -128,6 +146,9 @@ pub fn run(args: Args) -> Result<()> {
     if args.columns == 0 {
         Err(anyhow!("Must have at least 1 column"))?;
     }
+    if args.position_scale.is_nan() || args.position_scale <= 0. {
+        Err(anyhow!("Position scale must be positive"))?;
+    }
     writeln!(output, "{{")?;
     writeln!(output, "%% Auto generated by `cgt-cli`")?;
     writeln!(output, "%% Make sure to include preamble from README.md")?;
@@ -155,9 +176,10 @@ pub fn run(args: Args) -> Result<()> {
                 }
                 write!(
                     output,
-                    "{} & ${}$ ",
-                    entry.grid.to_latex_with_scale(args.position_scale),
-                    entry.temperature
+                    "{}",
+                    entry
+                        .grid
+                        .to_latex_row(&entry.temperature, args.position_scale)
                 )?;
             };
         }
@@ -0,0 +1,73 @@
+use crate::io::FileOrStdout;
+use anyhow::{bail, Context, Result};
+use cgt::{
+    numeric::dyadic_rational_number::DyadicRationalNumber,
+    short::partizan::{
+        games::domineering::Domineering,
+        transposition_table::{ParallelTranspositionTable, DEFAULT_CAPACITY},
+    },
+};
+use clap::Parser;
+use std::{
+    collections::BTreeMap,
+    io::{BufWriter, Write},
+};
+
+/// Header of CSV output
+const CSV_HEADER: &str = "filled,max_temperature,count";
+
+/// Compute the highest temperature among all domineering positions of given size, grouped by the
+/// number of filled tiles
+#[derive(Parser, Debug)]
+pub struct Args {
+    /// Domineering grid width
+    #[arg(long)]
+    width: u8,
+
+    /// Domineering grid height
+    #[arg(long)]
+    height: u8,
+
+    /// Output file with `filled,max_temperature,count` rows. Use '-' for stdout
+    #[arg(long, default_value = "-")]
+    out_file: FileOrStdout,
+
+    /// Number of positions to preallocate space for in the transposition table
+    #[arg(long, default_value_t = DEFAULT_CAPACITY)]
+    transposition_capacity: usize,
+}
+
+pub fn run(args: Args) -> Result<()> {
+    if args.width as u32 * args.height as u32 >= u64::BITS {
+        bail!("Grid cannot have more than 63 tiles");
+    }
+    let tiles = args.width as usize * args.height as usize;
+
+    let mut output = BufWriter::new(
+        args.out_file
+            .create()
+            .context("Could not open output file")?,
+    );
+
+    let transposition_table =
+        ParallelTranspositionTable::with_capacity(args.transposition_capacity);
+    let mut spectrum = BTreeMap::<usize, (DyadicRationalNumber, usize)>::new();
+    for (position, canonical_form) in
+        Domineering::all_canonical_forms(args.width, args.height, &transposition_table)
+    {
+        let temperature = canonical_form.temperature();
+        let (max_temperature, count) = spectrum
+            .entry(tiles - position.free_places())
+            .or_insert((temperature, 0));
+        *max_temperature = (*max_temperature).max(temperature);
+        *count += 1;
+    }
+
+    writeln!(output, "{CSV_HEADER}").context("Could not write output")?;
+    for (filled, (max_temperature, count)) in spectrum {
+        writeln!(output, "{filled},{max_temperature},{count}").context("Could not write output")?;
+    }
+
+    output.flush().context("Could not write output")?;
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use super::common::{DomineeringResult, OutputFormat, CSV_HEADER};
+use crate::io::{FileOrStdin, FileOrStdout};
+use anyhow::{Context, Result};
+use cgt::short::partizan::{
+    games::domineering::Domineering,
+    partizan_game::PartizanGame,
+    transposition_table::{ParallelTranspositionTable, DEFAULT_CAPACITY},
+};
+use clap::Parser;
+use std::{
+    io::{BufRead, BufReader, BufWriter, Write},
+    str::FromStr,
+};
+
+/// Compute canonical forms and temperatures of domineering positions from a file
+#[derive(Parser, Debug)]
+pub struct Args {
+    /// Input file with one position (e.g. '..#|##.|.#.') per line. Use '-' for stdin
+    #[arg(long)]
+    in_file: FileOrStdin,
+
+    /// Output file with values. Use '-' for stdout
+    #[arg(long, default_value = "-")]
+    out_file: FileOrStdout,
+
+    /// Format of output values
+    #[arg(long, value_enum, default_value_t = OutputFormat::Json)]
+    format: OutputFormat,
+
+    /// Number of positions to preallocate space for in the transposition table
+    #[arg(long, default_value_t = DEFAULT_CAPACITY)]
+    transposition_capacity: usize,
+}
+
+pub fn run(args: Args) -> Result<()> {
+    let input = BufReader::new(args.in_file.open().context("Could not open input file")?);
+    let mut output = BufWriter::new(
+        args.out_file
+            .create()
+            .context("Could not open output file")?,
+    );
+
+    if let OutputFormat::Csv = args.format {
+        writeln!(output, "{CSV_HEADER}").context("Could not write output")?;
+    }
+
+    let transposition_table =
+        ParallelTranspositionTable::with_capacity(args.transposition_capacity);
+    for (line_idx, line) in input.lines().enumerate() {
+        let line = line.context("Could not read input")?;
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let position: Domineering = Domineering::from_str(line)
+            .ok()
+            .with_context(|| format!("Could not parse position in line {}", line_idx + 1))?;
+        let canonical_form = position.canonical_form(&transposition_table);
+        let result = DomineeringResult::new(
+            position.to_string(),
+            canonical_form.to_string(),
+            canonical_form.temperature().to_string(),
+        );
+
+        let row = match args.format {
+            OutputFormat::Json => serde_json::ser::to_string(&result)?,
+            OutputFormat::Csv => result.to_csv_row(&position, &canonical_form),
+        };
+        writeln!(output, "{row}").context("Could not write output")?;
+    }
+
+    output.flush().context("Could not write output")?;
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use crate::io::FileOrStdout;
+use anyhow::{Context, Result};
+use cgt::{
+    drawing::svg::Svg,
+    short::partizan::{
+        canonical_form::CanonicalForm,
+        games::{
+            domineering::Domineering, fission::Fission, ski_jumps::SkiJumps,
+            toads_and_frogs::ToadsAndFrogs,
+        },
+    },
+};
+use clap::{Parser, ValueEnum};
+use std::{io::Write, str::FromStr};
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Game {
+    Domineering,
+    SkiJumps,
+    Fission,
+    ToadsAndFrogs,
+    /// Render thermograph of a canonical form
+    Thermograph,
+}
+
+/// Render game position as SVG image
+#[derive(Parser, Debug)]
+pub struct Args {
+    /// Type of the position to render
+    #[arg(long, value_enum)]
+    game: Game,
+
+    /// Position to render, in the same notation as the game's parser
+    #[arg(long)]
+    position: String,
+
+    /// Output SVG file. Use '-' for stdout
+    #[arg(long, default_value = "-")]
+    out: FileOrStdout,
+}
+
+fn render_svg<G>(position: &str) -> Result<String>
+where
+    G: FromStr + Svg,
+{
+    let position = G::from_str(position)
+        .ok()
+        .context(format!("Could not parse position: '{position}'"))?;
+    let mut buf = String::new();
+    position.to_svg(&mut buf)?;
+    Ok(buf)
+}
+
+pub fn run(args: Args) -> Result<()> {
+    let svg = match args.game {
+        Game::Domineering => render_svg::<Domineering>(&args.position)?,
+        Game::SkiJumps => render_svg::<SkiJumps>(&args.position)?,
+        Game::Fission => render_svg::<Fission>(&args.position)?,
+        Game::ToadsAndFrogs => render_svg::<ToadsAndFrogs>(&args.position)?,
+        Game::Thermograph => {
+            let canonical_form = CanonicalForm::from_str(&args.position)
+                .ok()
+                .context(format!("Could not parse game: '{}'", args.position))?;
+            let mut buf = String::new();
+            canonical_form.thermograph().to_svg(&mut buf)?;
+            buf
+        }
+    };
+
+    let mut output = args
+        .out
+        .create()
+        .context("Could not create/open output file")?;
+    output
+        .write_all(svg.as_bytes())
+        .context("Could not write output")?;
+
+    Ok(())
+}
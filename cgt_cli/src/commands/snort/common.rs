@@ -4,7 +4,8 @@ use cgt::{
     graph::undirected::Graph,
     numeric::{dyadic_rational_number::DyadicRationalNumber, rational::Rational},
     short::partizan::{
-        games::snort::Snort, partizan_game::PartizanGame,
+        games::snort::{Snort, SnortAnalysis},
+        partizan_game::PartizanGame,
         transposition_table::ParallelTranspositionTable,
     },
 };
@@ -78,8 +79,12 @@ fn dump_edges(w: &mut impl Write, graph: &Graph) -> io::Result<()> {
 
 pub fn analyze_position(position: Snort, with_graphviz: bool) -> Result<()> {
     let transposition_table = ParallelTranspositionTable::new();
-    let canonical_form = position.canonical_form(&transposition_table);
-    let temperature = canonical_form.temperature();
+    let SnortAnalysis {
+        canonical_form,
+        temperature,
+        degree,
+        fitness: score,
+    } = position.analysis(&transposition_table);
 
     let timestamp = time::SystemTime::now()
         .duration_since(time::SystemTime::UNIX_EPOCH)
@@ -120,9 +125,7 @@ pub fn analyze_position(position: Snort, with_graphviz: bool) -> Result<()> {
         dump_edges(&mut stderr(), &m.graph)?;
     }
 
-    let degree = position.degree();
     let second_degree = position.second_degree();
-    let score = temperature.to_rational() - Rational::from(degree as i32);
 
     eprintln!("Canonical Form: {}", canonical_form);
     eprintln!("Temperature: {}", temperature);
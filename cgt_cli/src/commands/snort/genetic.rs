@@ -5,9 +5,9 @@ use cgt::{
     graph::undirected,
     numeric::rational::Rational,
     short::partizan::{
-        games::snort::{Snort, VertexColor, VertexKind},
+        games::snort::{Snort, VertexColor},
         partizan_game::PartizanGame,
-        transposition_table::ParallelTranspositionTable,
+        transposition_table::{ParallelTranspositionTable, DEFAULT_CAPACITY},
     },
 };
 use clap::{self, Parser};
@@ -55,6 +55,10 @@ pub struct Args {
     /// Save if score is above that value
     #[arg(long, default_value_t = Rational::from(0))]
     save_eq_or_above: Rational,
+
+    /// Number of positions to preallocate space for in the transposition table
+    #[arg(long, default_value_t = DEFAULT_CAPACITY)]
+    transposition_capacity: usize,
 }
 
 struct SnortTemperatureDegreeDifference {
@@ -75,22 +79,16 @@ impl SnortTemperatureDegreeDifference {
             let mutation_roll: f32 = rng.gen();
             if mutation_roll < mutation_rate {
                 let to_remove = rng.gen_range(0..position.graph.size());
-                position.graph.remove_vertex(to_remove);
-                position.vertices.remove(to_remove);
+                position.remove_vertex(to_remove);
             }
         }
         // TODO: Check for max size
         // if position.graph.size()
         let mutation_roll: f32 = rng.gen();
         if mutation_roll < mutation_rate {
-            position.graph.add_vertex();
-            position
-                .vertices
-                .push(VertexKind::Single(VertexColor::Empty));
-            let another_vertex = rng.gen_range(0..position.graph.size() - 1);
-            position
-                .graph
-                .connect(position.graph.size() - 1, another_vertex, true);
+            let new_vertex = position.add_vertex();
+            let another_vertex = rng.gen_range(0..new_vertex);
+            position.add_edge(new_vertex, another_vertex);
         }
 
         // Mutate edges
@@ -102,9 +100,11 @@ impl SnortTemperatureDegreeDifference {
 
                 let mutation_roll: f32 = rng.gen();
                 if mutation_roll < mutation_rate {
-                    position
-                        .graph
-                        .connect(v, u, !position.graph.are_adjacent(v, u));
+                    if position.graph.are_adjacent(v, u) {
+                        position.remove_edge(v, u);
+                    } else {
+                        position.add_edge(v, u);
+                    }
                 }
             }
         }
@@ -118,7 +118,7 @@ impl SnortTemperatureDegreeDifference {
         for idx in 0..position.vertices.len() {
             let mutation_roll: f32 = rng.gen();
             if mutation_roll < mutation_rate {
-                position.vertices[idx] = VertexKind::Single(*available_colors.choose(rng).unwrap());
+                position.color_vertex(idx, *available_colors.choose(rng).unwrap());
             }
         }
     }
@@ -169,10 +169,7 @@ impl Algorithm<Snort, Rational> for SnortTemperatureDegreeDifference {
             return Rational::NegativeInfinity;
         }
 
-        let game = position.canonical_form(&self.transposition_table);
-        let temp = game.temperature();
-        let degree = position.degree();
-        temp.to_rational() - Rational::from(degree as i64)
+        position.analysis(&self.transposition_table).fitness
     }
 
     fn random(&self, rng: &mut rand::rngs::ThreadRng) -> Snort {
@@ -248,7 +245,7 @@ fn seed_positions() -> Vec<Snort> {
 
 pub fn run(args: Args) -> Result<()> {
     let alg = SnortTemperatureDegreeDifference {
-        transposition_table: ParallelTranspositionTable::new(),
+        transposition_table: ParallelTranspositionTable::with_capacity(args.transposition_capacity),
         max_graph_vertices: args.max_graph_vertices,
         mutation_rate: args.mutation_rate,
     };
@@ -53,6 +53,15 @@ pub struct Args {
     fitness_lower_bound: Option<Rational>,
 }
 
+/// Render fitness score in LaTeX math mode
+fn latex_score(score: Rational) -> String {
+    match score {
+        Rational::NegativeInfinity => "-\\infty".to_owned(),
+        Rational::PositiveInfinity => "\\infty".to_owned(),
+        Rational::Value(_) => score.to_ascii_string(),
+    }
+}
+
 pub fn run(args: Args) -> Result<()> {
     let input = BufReader::new(args.in_file.open().context("Could not open input file")?);
     let mut output = BufWriter::new(args.out_file.open().context("Could not open output file")?);
@@ -172,7 +181,11 @@ pub fn run(args: Args) -> Result<()> {
                 write!(
                     output,
                     "\\includegraphics[width={}]{{{}}} & ${}$ & ${}$ & ${}$ ",
-                    args.image_width, entry.3, entry.1, entry.2, entry.0.score
+                    args.image_width,
+                    entry.3,
+                    entry.1,
+                    entry.2,
+                    latex_score(entry.0.score)
                 )?;
             };
         }
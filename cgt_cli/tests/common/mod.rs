@@ -0,0 +1,21 @@
+//! Helpers shared by `cgt-cli` integration tests
+
+use std::process::Command;
+
+/// Run `cgt-cli` with given arguments and fail the test if the command fails
+pub fn cgt_cli(args: &[&str]) {
+    let output = Command::new(env!("CARGO_BIN_EXE_cgt-cli"))
+        .args(args)
+        .output()
+        .expect("Could not run cgt-cli");
+    assert!(
+        output.status.success(),
+        "cgt-cli {args:?} failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+/// Fresh temporary directory, removed when dropped, also when the test panics
+pub fn temp_dir() -> tempfile::TempDir {
+    tempfile::tempdir().expect("Could not create temporary directory")
+}
@@ -0,0 +1,80 @@
+//! Domineering subcommands run end to end on files
+
+mod common;
+
+use common::{cgt_cli, temp_dir};
+use std::fs;
+
+#[test]
+fn values_multiple_positions() {
+    let dir = temp_dir();
+    let in_file = dir.path().join("positions.txt");
+    let out_file = dir.path().join("values.txt");
+    fs::write(&in_file, "..|..\n.#|..\n\n...\n").unwrap();
+
+    for (format, expected) in [
+        (
+            "json",
+            vec![
+                r#"{"version":1,"grid":"..|..","canonical_form":"{1|-1}","temperature":"1"}"#,
+                r#"{"version":1,"grid":".#|..","canonical_form":"*","temperature":"0"}"#,
+                r#"{"version":1,"grid":"...","canonical_form":"-1","temperature":"-1"}"#,
+            ],
+        ),
+        (
+            "csv",
+            vec![
+                "width,height,grid,temperature,canonical_form,mean",
+                r#"2,2,..|..,1,"{1|-1}",0"#,
+                "2,2,.#|..,0,*,0",
+                "3,1,...,-1,-1,-1",
+            ],
+        ),
+    ] {
+        cgt_cli(&[
+            "domineering",
+            "value",
+            "--in-file",
+            in_file.to_str().unwrap(),
+            "--out-file",
+            out_file.to_str().unwrap(),
+            "--format",
+            format,
+        ]);
+
+        let output = fs::read_to_string(&out_file).unwrap();
+        assert_eq!(output.lines().collect::<Vec<_>>(), expected);
+    }
+}
+
+#[test]
+fn spectrum_small_board() {
+    let dir = temp_dir();
+    let out_file = dir.path().join("spectrum.csv");
+
+    cgt_cli(&[
+        "domineering",
+        "spectrum",
+        "--width",
+        "2",
+        "--height",
+        "2",
+        "--out-file",
+        out_file.to_str().unwrap(),
+        "--transposition-capacity",
+        "1",
+    ]);
+
+    let output = fs::read_to_string(&out_file).unwrap();
+    assert_eq!(
+        output.lines().collect::<Vec<_>>(),
+        vec![
+            "filled,max_temperature,count",
+            "0,1,1",
+            "1,0,4",
+            "2,-1,6",
+            "3,-1,4",
+            "4,-1,1"
+        ]
+    );
+}
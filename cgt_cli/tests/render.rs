@@ -0,0 +1,24 @@
+//! Rendering positions to files
+
+mod common;
+
+use common::{cgt_cli, temp_dir};
+
+#[test]
+fn renders_domineering_to_file() {
+    let dir = temp_dir();
+    let out = dir.path().join("position.svg");
+
+    cgt_cli(&[
+        "render",
+        "--game",
+        "domineering",
+        "--position",
+        "..|.#",
+        "--out",
+        out.to_str().unwrap(),
+    ]);
+
+    let svg = std::fs::read_to_string(&out).unwrap();
+    assert!(svg.starts_with("<svg"));
+}
@@ -1,4 +1,6 @@
-use crate::{dyadic_rational_number::PyDyadicRationalNumber, thermograph::PyThermograph};
+use crate::{
+    dyadic_rational_number::PyDyadicRationalNumber, nimber::PyNimber, thermograph::PyThermograph,
+};
 use cgt::short::partizan::canonical_form::CanonicalForm;
 use pyo3::{prelude::*, pyclass::CompareOp};
 use std::{
@@ -32,6 +34,26 @@ impl PyCanonicalForm {
         ))
     }
 
+    #[staticmethod]
+    fn nimber(nimber: &PyNimber) -> Self {
+        Self::from(CanonicalForm::from(nimber.inner))
+    }
+
+    #[staticmethod]
+    fn switch(n: &PyDyadicRationalNumber) -> Self {
+        Self::from(CanonicalForm::switch(&n.inner))
+    }
+
+    #[staticmethod]
+    fn tiny(g: &Self) -> Self {
+        Self::from(CanonicalForm::tiny(&g.inner))
+    }
+
+    #[staticmethod]
+    fn miny(g: &Self) -> Self {
+        Self::from(CanonicalForm::miny(&g.inner))
+    }
+
     fn __repr__(&self) -> String {
         format!("CanonicalForm('{}')", self.inner)
     }
@@ -61,4 +83,26 @@ impl PyCanonicalForm {
     fn thermograph(&self) -> PyThermograph {
         PyThermograph::from(self.inner.thermograph())
     }
+
+    fn number_part(&self) -> Self {
+        Self::from(self.inner.number_part())
+    }
+
+    fn infinitesimal_part(&self) -> Self {
+        Self::from(self.inner.infinitesimal_part())
+    }
+
+    fn thermal_class(&self) -> String {
+        self.inner.thermal_class().to_string()
+    }
+
+    fn as_switch(&self) -> Option<PyDyadicRationalNumber> {
+        self.inner.as_switch().map(PyDyadicRationalNumber::from)
+    }
+
+    fn nimber_difference(&self, other: &Self) -> Option<PyNimber> {
+        self.inner
+            .nimber_difference(&other.inner)
+            .map(PyNimber::from)
+    }
 }
@@ -19,7 +19,7 @@ macro_rules! wrap_struct {
         #[pyclass(name = $py_class)]
         #[repr(transparent)]
         pub struct $py_struct {
-            inner: $struct,
+            pub(crate) inner: $struct,
         }
 
         impl From<$struct> for $py_struct {
@@ -40,10 +40,9 @@ macro_rules! impl_py_partizan_game {
         impl $py_game {
             #[new]
             fn py_new(position: &str) -> PyResult<Self> {
-                let inner = $game::from_str(position)
-                    .or(Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                        "Parse error",
-                    )))?;
+                let inner = $game::from_str(position).map_err(|err| {
+                    PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Parse error: {err}"))
+                })?;
                 Ok(Self::from(inner))
             }
 
@@ -64,6 +63,10 @@ macro_rules! impl_py_partizan_game {
                 $py_tt::default()
             }
 
+            fn value(&self) -> PyCanonicalForm {
+                PyCanonicalForm::from(self.inner.value())
+            }
+
             fn canonical_form(&self, transposition_table: Option<&$py_tt>) -> PyCanonicalForm {
                 match transposition_table {
                     Some(transposition_table) => {
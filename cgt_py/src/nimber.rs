@@ -23,6 +23,23 @@ impl PyNimber {
         Self::from(Sub::sub(&self.inner, &other.inner))
     }
 
+    fn __mul__(&self, other: &Self) -> Self {
+        Self::from(self.inner.nim_mul(other.inner))
+    }
+
+    fn binary_components(&self) -> Vec<PyNimber> {
+        self.inner
+            .binary_components()
+            .into_iter()
+            .map(PyNimber::from)
+            .collect()
+    }
+
+    #[staticmethod]
+    fn mex(nimbers: Vec<PyNimber>) -> PyNimber {
+        mex(nimbers)
+    }
+
     fn __neg__(&self) -> Self {
         Self::from(Neg::neg(&self.inner))
     }
@@ -41,3 +58,25 @@ pub fn mex(nimbers: Vec<PyNimber>) -> PyNimber {
             .collect(),
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nim_mul_works() {
+        let lhs = PyNimber::py_new(2);
+        let rhs = PyNimber::py_new(3);
+        assert_eq!(lhs.__mul__(&rhs).inner, Nimber::new(1));
+    }
+
+    #[test]
+    fn mex_works() {
+        let nimbers = vec![
+            PyNimber::py_new(0),
+            PyNimber::py_new(1),
+            PyNimber::py_new(3),
+        ];
+        assert_eq!(PyNimber::mex(nimbers).inner, Nimber::new(2));
+    }
+}
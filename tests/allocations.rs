@@ -0,0 +1,33 @@
+//! Heap allocation counts of hot paths, measured with `dhat`
+
+use cgt::short::partizan::{games::domineering::Domineering, partizan_game::PartizanGame};
+use std::str::FromStr;
+
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
+
+#[test]
+fn left_moves_allocations() {
+    let _profiler = dhat::Profiler::builder().testing().build();
+
+    // 5 moves for Left, fits inline in `MoveList`
+    let position: Domineering = Domineering::from_str("..#|..#|#..|#..").unwrap();
+
+    let before = dhat::HeapStats::get().total_blocks;
+    let moves = position.left_moves();
+    let after = dhat::HeapStats::get().total_blocks;
+    assert_eq!(moves.len(), 5);
+
+    #[cfg(feature = "smallvec")]
+    dhat::assert_eq!(after - before, 0);
+    #[cfg(not(feature = "smallvec"))]
+    dhat::assert!(after - before > 0);
+
+    // Counting moves never allocates. Top left domino fills the first row, so some positions are
+    // built while counting.
+    let before = dhat::HeapStats::get().total_blocks;
+    let num_moves = position.num_left_moves();
+    let after = dhat::HeapStats::get().total_blocks;
+    assert_eq!(num_moves, 5);
+    dhat::assert_eq!(after - before, 0);
+}